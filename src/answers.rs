@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Hint;
+
+/// Default location of the answers store, relative to the repository root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted and rejected answers for each day and part, stored as `[dayNN.partN]` tables in a TOML file.
+pub struct AnswerStore {
+    path: PathBuf,
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<PartAnswers>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<PartAnswers>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    /// Answers the website rejected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<String>,
+    /// Smallest answer the website said was too high.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_high: Option<i64>,
+    /// Largest answer the website said was too low.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_low: Option<i64>,
+}
+
+/// Outcome of comparing a computed answer against the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    New,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`, starting empty if the file doesn't exist yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let days = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", path.display()))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents = toml::to_string(&self.days).context("Failed to serialize answers")?;
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&self.path, contents).with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    pub fn get(&self, day: u32, part: u8) -> Option<&str> {
+        self.part(day, part)?.answer.as_deref()
+    }
+
+    pub fn check(&self, day: u32, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+            None => Verdict::New,
+        }
+    }
+
+    /// Stores `answer` as the accepted answer, replacing any previous one.
+    pub fn record(&mut self, day: u32, part: u8, answer: &str) {
+        self.part_mut(day, part).answer = Some(answer.to_string());
+    }
+
+    /// Records an answer the website rejected, narrowing the known bounds if it gave a hint.
+    pub fn record_wrong(&mut self, day: u32, part: u8, answer: &str, hint: Option<Hint>) {
+        let entry = self.part_mut(day, part);
+        if !entry.wrong.iter().any(|wrong| wrong == answer) {
+            entry.wrong.push(answer.to_string());
+        }
+
+        match (hint, answer.parse::<i64>()) {
+            (Some(Hint::TooHigh), Ok(value)) => entry.too_high = Some(entry.too_high.map_or(value, |high| high.min(value))),
+            (Some(Hint::TooLow), Ok(value)) => entry.too_low = Some(entry.too_low.map_or(value, |low| low.max(value))),
+            _ => {}
+        }
+    }
+
+    /// Explains why submitting `answer` would be pointless, if the store already knows how it would go.
+    pub fn known_outcome(&self, day: u32, part: u8, answer: &str) -> Option<String> {
+        let entry = self.part(day, part)?;
+
+        if let Some(accepted) = &entry.answer {
+            return Some(if accepted == answer {
+                format!("{answer} has already been accepted")
+            } else {
+                format!("this part was already solved with {accepted}")
+            });
+        }
+
+        if entry.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(format!("{answer} was already submitted and is wrong"));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            if let Some(high) = entry.too_high.filter(|&high| value >= high) {
+                return Some(format!("{answer} is not below {high}, which was too high"));
+            }
+            if let Some(low) = entry.too_low.filter(|&low| value <= low) {
+                return Some(format!("{answer} is not above {low}, which was too low"));
+            }
+        }
+
+        None
+    }
+
+    fn part(&self, day: u32, part: u8) -> Option<&PartAnswers> {
+        let answers = self.days.get(&day_key(day))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn part_mut(&mut self, day: u32, part: u8) -> &mut PartAnswers {
+        let answers = self.days.entry(day_key(day)).or_default();
+        match part {
+            1 => answers.part1.get_or_insert_with(Default::default),
+            2 => answers.part2.get_or_insert_with(Default::default),
+            _ => panic!("Part must be 1 or 2, got {part}"),
+        }
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}-{name}.toml", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn verdicts() {
+        let mut store = AnswerStore::load(temp_store("verdicts")).unwrap();
+        assert_eq!(Verdict::New, store.check(6, 1, "41"));
+
+        store.record(6, 1, "41");
+        assert_eq!(Verdict::Pass, store.check(6, 1, "41"));
+        assert_eq!(Verdict::Fail { expected: "41".to_string() }, store.check(6, 1, "42"));
+        assert_eq!(Verdict::New, store.check(6, 2, "41"));
+    }
+
+    #[test]
+    fn known_outcomes() {
+        let mut store = AnswerStore::load(temp_store("known-outcomes")).unwrap();
+        assert_eq!(None, store.known_outcome(7, 1, "100"));
+
+        store.record_wrong(7, 1, "100", Some(Hint::TooHigh));
+        store.record_wrong(7, 1, "80", Some(Hint::TooHigh));
+        store.record_wrong(7, 1, "10", Some(Hint::TooLow));
+        store.record_wrong(7, 1, "50", None);
+
+        assert!(store.known_outcome(7, 1, "50").unwrap().contains("already submitted"));
+        assert!(store.known_outcome(7, 1, "90").unwrap().contains("too high"));
+        assert!(store.known_outcome(7, 1, "85").unwrap().contains("too high"));
+        assert!(store.known_outcome(7, 1, "5").unwrap().contains("too low"));
+        assert_eq!(None, store.known_outcome(7, 1, "60"));
+
+        store.record(7, 1, "60");
+        assert!(store.known_outcome(7, 1, "60").unwrap().contains("already been accepted"));
+        assert!(store.known_outcome(7, 1, "61").unwrap().contains("already solved"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = temp_store("round-trip");
+        let mut store = AnswerStore::load(&path).unwrap();
+        store.record(6, 1, "41");
+        store.record(18, 2, "6,1");
+        store.record_wrong(18, 1, "300", Some(Hint::TooHigh));
+        store.save().unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("[day06.part1]"));
+
+        let store = AnswerStore::load(&path).unwrap();
+        assert_eq!(Some("41"), store.get(6, 1));
+        assert_eq!(None, store.get(6, 2));
+        assert_eq!(Some("6,1"), store.get(18, 2));
+        assert!(store.known_outcome(18, 1, "301").is_some());
+
+        fs::remove_file(path).unwrap();
+    }
+}
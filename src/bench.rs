@@ -0,0 +1,284 @@
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::DynSolution;
+
+/// Default location of the timing history, relative to the repository root.
+pub const HISTORY_FILE: &str = "bench_history.toml";
+
+/// Slowdowns smaller than this are put down to noise, however large they are relatively.
+const NOISE_FLOOR: Duration = Duration::from_micros(20);
+
+/// Summary of repeated timings of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, or `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) { (sorted[mid - 1] + sorted[mid]) / 2 } else { sorted[mid] };
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = samples.iter().map(|sample| (sample.as_secs_f64() - mean).powi(2)).sum::<f64>() / n;
+
+        Some(Self {
+            median,
+            min: sorted[0],
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "median {:>10.2?}  min {:>10.2?}  ± {:.2?}", self.median, self.min, self.stddev)
+    }
+}
+
+/// Timings of each step of one day, over some number of iterations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTimings {
+    pub day: u32,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayTimings {
+    /// The typical time for the whole day, from the medians of each step.
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.median
+    }
+}
+
+/// Times parsing and both parts of `day` on `input` separately, `iterations` times each.
+pub fn bench(day: &dyn DynSolution, input: &[u8], iterations: usize) -> Result<DayTimings> {
+    ensure!(iterations > 0, "Benchmarks need at least one iteration");
+
+    let (mut parse, mut part1, mut part2) = (Vec::new(), Vec::new(), Vec::new());
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = day.parse(input)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        day.part1(parsed.as_ref())?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        day.part2(parsed.as_ref())?;
+        part2.push(start.elapsed());
+    }
+
+    let stats = |samples: &[Duration]| Stats::from_samples(samples).expect("At least one iteration ran");
+    Ok(DayTimings {
+        day: day.day(),
+        iterations,
+        parse: stats(&parse),
+        part1: stats(&part1),
+        part2: stats(&part2),
+    })
+}
+
+/// One benchmark run of a day as kept in the history, with median times in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub timestamp: String,
+    pub day: u32,
+    pub iterations: usize,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+impl Record {
+    pub fn new(timestamp: impl Into<String>, timings: &DayTimings) -> Self {
+        Self {
+            timestamp: timestamp.into(),
+            day: timings.day,
+            iterations: timings.iterations,
+            parse_ns: timings.parse.median.as_nanos() as u64,
+            part1_ns: timings.part1.median.as_nanos() as u64,
+            part2_ns: timings.part2.median.as_nanos() as u64,
+        }
+    }
+
+    fn steps(&self) -> [(&'static str, Duration); 3] {
+        [
+            ("parse", Duration::from_nanos(self.parse_ns)),
+            ("part 1", Duration::from_nanos(self.part1_ns)),
+            ("part 2", Duration::from_nanos(self.part2_ns)),
+        ]
+    }
+}
+
+/// A step that got slower than the threshold allows since the previous run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub step: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    /// How much slower the step got, as a percentage.
+    pub fn slowdown(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:.2?} -> {:.2?} (+{:.0}%)", self.step, self.before, self.after, self.slowdown())
+    }
+}
+
+/// Every benchmark run so far, stored as `[[run]]` tables that new runs are appended to.
+pub struct History {
+    path: PathBuf,
+    runs: Vec<Record>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct HistoryFile {
+    #[serde(default)]
+    run: Vec<Record>,
+}
+
+impl History {
+    /// Loads the history at `path`, starting empty if the file doesn't exist yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file: HistoryFile = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => HistoryFile::default(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        Ok(Self { path, runs: file.run })
+    }
+
+    pub fn runs(&self) -> &[Record] {
+        &self.runs
+    }
+
+    /// The most recent run of `day`.
+    pub fn latest(&self, day: u32) -> Option<&Record> {
+        self.runs.iter().rev().find(|run| run.day == day)
+    }
+
+    /// Adds `record` to the end of the file, leaving earlier runs untouched.
+    pub fn append(&mut self, record: Record) -> Result<()> {
+        let table = toml::to_string(&HistoryFile { run: vec![record.clone()] }).context("Failed to serialize timings")?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        writeln!(file, "{table}").with_context(|| format!("Failed to write {}", self.path.display()))?;
+
+        self.runs.push(record);
+        Ok(())
+    }
+}
+
+/// Steps of `current` that are more than `threshold` percent slower than in `previous`.
+pub fn regressions(previous: &Record, current: &Record, threshold: f64) -> Vec<Regression> {
+    previous
+        .steps()
+        .into_iter()
+        .zip(current.steps())
+        .filter(|&((_, before), (_, after))| {
+            after > before + NOISE_FLOOR && after.as_secs_f64() > before.as_secs_f64() * (1.0 + threshold / 100.0)
+        })
+        .map(|((step, before), (_, after))| Regression { step, before, after })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn record(parse: u64, part1: u64, part2: u64) -> Record {
+        Record {
+            timestamp: "2024-12-06T05:00:00Z".to_string(),
+            day: 6,
+            iterations: 10,
+            parse_ns: ms(parse).as_nanos() as u64,
+            part1_ns: ms(part1).as_nanos() as u64,
+            part2_ns: ms(part2).as_nanos() as u64,
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(8), ms(6)]).unwrap();
+        assert_eq!(ms(5), stats.median);
+        assert_eq!(ms(2), stats.min);
+        assert_eq!(ms(5), stats.mean);
+        // The square root of 5ms².
+        assert_eq!(2236, stats.stddev.as_micros());
+
+        assert_eq!(ms(3), Stats::from_samples(&[ms(3)]).unwrap().median);
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let before = record(1, 10, 100);
+        assert!(regressions(&before, &record(1, 10, 105), 10.0).is_empty());
+
+        let found = regressions(&before, &record(2, 9, 150), 10.0);
+        assert_eq!(vec!["parse", "part 2"], found.iter().map(|r| r.step).collect::<Vec<_>>());
+        assert_eq!(50.0, found[1].slowdown().round());
+
+        // Doubling a few microseconds is noise, not a regression.
+        let tiny = Record { parse_ns: 2_000, ..before.clone() };
+        assert!(regressions(&Record { parse_ns: 1_000, ..before }, &tiny, 10.0).is_empty());
+    }
+
+    #[test]
+    fn history_appends() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.toml", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        assert!(history.runs().is_empty());
+        history.append(record(1, 2, 3)).unwrap();
+        history.append(Record { day: 7, ..record(4, 5, 6) }).unwrap();
+        history.append(record(7, 8, 9)).unwrap();
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(3, history.runs().len());
+        assert_eq!(record(7, 8, 9), *history.latest(6).unwrap());
+        assert_eq!(7, history.latest(7).unwrap().day);
+        assert!(history.latest(8).is_none());
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,468 @@
+use std::any::Any;
+use std::io::{self, ErrorKind};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use adv_code_2024::answers::{AnswerStore, Verdict};
+use adv_code_2024::bench::{self, History, Record};
+use adv_code_2024::cache::InputCache;
+use adv_code_2024::client::AocClient;
+use adv_code_2024::config::{Config, Profile, CONFIG_FILE};
+use adv_code_2024::days;
+use adv_code_2024::input::InputSource;
+use adv_code_2024::parallel;
+use adv_code_2024::parse::ParseError;
+use adv_code_2024::puzzle;
+use adv_code_2024::report::Report;
+use adv_code_2024::scaffold::{Scaffold, TITLE_PLACEHOLDER};
+use adv_code_2024::schedule::{self, Schedule, SystemClock, LAST_DAY};
+use adv_code_2024::*;
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Keep everything on one thread for deterministic timings, even when built with the `parallel` feature
+    #[arg(long, global = true)]
+    single_threaded: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for one or more days against their inputs
+    Run {
+        /// Days to run, either a single day (`6`) or a range (`1..=10`, `1..10`)
+        #[arg(value_parser = parse_days, required_unless_present = "all")]
+        days: Vec<RangeInclusive<u32>>,
+
+        /// Run every solved day
+        #[arg(short, long, conflicts_with = "days")]
+        all: bool,
+
+        /// Record answers that aren't in the answers store yet as accepted
+        #[arg(long)]
+        accept: bool,
+
+        /// Answers store to check results against, instead of the profile's
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        #[command(flatten)]
+        source: InputArgs,
+
+        /// Run each day on every input for it in DIR, `NN.txt` and any `NN.<name>.txt`
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["input", "alt"])]
+        input_dir: Option<PathBuf>,
+
+        /// Profile whose inputs and answers to use
+        #[arg(short, long, default_value = Profile::DEFAULT)]
+        profile: String,
+
+        /// Check each day against the inputs and answers of every profile in the config
+        #[arg(long, conflicts_with_all = ["profile", "answers", "input", "alt", "input_dir"])]
+        all_profiles: bool,
+    },
+    /// Time parsing and both parts of one or more days over several iterations
+    Bench {
+        /// Days to benchmark, either a single day (`6`) or a range (`1..=10`, `1..10`)
+        #[arg(value_parser = parse_days, required_unless_present = "all")]
+        days: Vec<RangeInclusive<u32>>,
+
+        /// Benchmark every solved day
+        #[arg(short, long, conflicts_with = "days")]
+        all: bool,
+
+        /// Times to run each day
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Flag steps whose median got more than this many percent slower since the last recorded run
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Timing history to compare against and append to
+        #[arg(long, default_value = bench::HISTORY_FILE)]
+        history: PathBuf,
+
+        /// Compare against the history without recording this run
+        #[arg(long)]
+        no_save: bool,
+
+        #[command(flatten)]
+        source: InputArgs,
+    },
+    /// Run every solved day and check the total time against the budget in `aoc.toml`
+    Report {
+        /// Times to run each day, reporting the median
+        #[arg(short = 'n', long, default_value_t = 3)]
+        iterations: usize,
+
+        /// Budget for the whole season in milliseconds, instead of `budget_ms` from the config
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        budget: Option<u64>,
+    },
+    /// Generate a new day from `template/day.rs` and register it with the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=LAST_DAY as i64))]
+        day: u32,
+
+        /// Puzzle title, taken from the puzzle page when fetching
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Also download the input, the puzzle description and its example
+        #[arg(short, long)]
+        fetch: bool,
+    },
+}
+
+/// Which input to run on, when it isn't the cached `input/NN.txt`.
+#[derive(clap::Args)]
+struct InputArgs {
+    /// Read the input from PATH instead, or from stdin if PATH is `-`
+    #[arg(long, value_name = "PATH", conflicts_with = "alt")]
+    input: Option<String>,
+
+    /// Use the alternate input `input/NN.<NAME>.txt`, such as someone else's
+    #[arg(long, value_name = "NAME")]
+    alt: Option<String>,
+}
+
+impl InputArgs {
+    /// The chosen source, checking it can be used for every selected day.
+    fn source(self, days: usize) -> Result<InputSource> {
+        let source = match (self.input, self.alt) {
+            (Some(path), _) => InputSource::from_arg(&path),
+            (None, Some(name)) => InputSource::Alternate(name),
+            (None, None) => InputSource::Cached,
+        };
+        if source.is_single_input() && days > 1 {
+            bail!("--input can only be used with a single day");
+        }
+        Ok(source)
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    parallel::set_single_threaded(args.single_threaded);
+
+    match args.command {
+        Command::Run { days, all, accept, answers, source, input_dir, profile, all_profiles } => {
+            let selected = select_days(days, all)?;
+            let config = Config::load(CONFIG_FILE)?;
+            let profiles = if all_profiles { config.all_profiles() } else { vec![config.profile(&profile)?] };
+            let mut stores = profiles
+                .iter()
+                .map(|profile| AnswerStore::load(answers.as_ref().unwrap_or(&profile.answers)))
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut jobs = Vec::new();
+            let mut skipped = Vec::new();
+            if let Some(dir) = input_dir {
+                let cache = InputCache::new(dir);
+                for day in selected {
+                    jobs.extend(cache.inputs(day.day())?.into_iter().map(|path| Job { day, source: InputSource::File(path), profile: 0 }));
+                }
+            } else if all_profiles {
+                for day in selected {
+                    for (index, profile) in profiles.iter().enumerate() {
+                        if profile.cache().is_cached(day.day()) {
+                            jobs.push(Job { day, source: InputSource::Cached, profile: index });
+                        } else {
+                            skipped.push(format!("day {} for {}", day.day(), profile.name));
+                        }
+                    }
+                }
+            } else {
+                let source = source.source(selected.len())?;
+                jobs.extend(selected.into_iter().map(|day| Job { day, source: source.clone(), profile: 0 }));
+            }
+
+            let mut failed = 0;
+            let mut wrong = 0;
+            let mut broken = Vec::new();
+            let mut print = |job: &Job, run| {
+                let profile = &profiles[job.profile];
+                let input = format!("day {} on {}", job.day.day(), job.source.describe(&profile.cache(), job.day.day()));
+                match print_day(job, profile, run, &mut stores[job.profile], accept) {
+                    Ok(verdicts) => {
+                        for (part, verdict) in (1..=2).zip(verdicts) {
+                            if let Verdict::Fail { .. } = verdict {
+                                broken.push(format!("{input}: part {part} {verdict}"));
+                                wrong += 1;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Error: {e:#}");
+                        if let Some(parse_error) = e.chain().find_map(|cause| cause.downcast_ref::<ParseError>()) {
+                            eprintln!("{}", parse_error.snippet());
+                        }
+                        broken.push(format!("{input}: {e:#}"));
+                        failed += 1;
+                    }
+                }
+                println!();
+            };
+
+            if parallel::is_parallel() {
+                // Solve every day at once, then print them in order so their output doesn't interleave.
+                let runs = parallel::map(&jobs, |job| solve_day(job.day, &job.source, &profiles[job.profile].cache()));
+                for (job, run) in jobs.iter().zip(runs) {
+                    print(job, run);
+                }
+            } else {
+                for job in &jobs {
+                    print(job, solve_day(job.day, &job.source, &profiles[job.profile].cache()));
+                }
+            }
+
+            if accept {
+                for store in &stores {
+                    store.save()?;
+                }
+            }
+
+            if !skipped.is_empty() {
+                println!("Skipped profiles without an input: {}", skipped.join(", "));
+            }
+            if jobs.len() > 1 && !broken.is_empty() {
+                println!("Inputs that break a solution:");
+                for input in &broken {
+                    println!("  {input}");
+                }
+            }
+            if failed > 0 {
+                bail!("{failed} run(s) failed");
+            }
+            if wrong > 0 {
+                bail!("{wrong} answer(s) don't match the answers store");
+            }
+        }
+        Command::Bench { days, all, iterations, threshold, history, no_save, source } => {
+            let selected = select_days(days, all)?;
+            let source = source.source(selected.len())?;
+            // Timings on another input aren't comparable with the history of the cached one.
+            let compare = source.is_cached();
+            let mut history = History::load(history)?;
+            let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+            let mut regressed = 0;
+            for day in selected {
+                let timings = bench::bench(day, &source.read(&InputCache::default(), day.day())?, iterations)?;
+                println!("Day {:02} ({} iterations)", timings.day, timings.iterations);
+                println!("  parse   {}", timings.parse);
+                println!("  part 1  {}", timings.part1);
+                println!("  part 2  {}", timings.part2);
+
+                if !compare {
+                    continue;
+                }
+                let record = Record::new(&timestamp, &timings);
+                if let Some(previous) = history.latest(day.day()) {
+                    for regression in bench::regressions(previous, &record, threshold) {
+                        println!("  REGRESSION since {}: {regression}", previous.timestamp);
+                        regressed += 1;
+                    }
+                }
+                if !no_save {
+                    history.append(record)?;
+                }
+            }
+
+            if regressed > 0 {
+                bail!("{regressed} step(s) got more than {threshold}% slower");
+            }
+        }
+        Command::Report { iterations, budget } => {
+            let budget = match budget {
+                Some(budget) => budget,
+                None => Config::load(CONFIG_FILE)?.budget_ms,
+            };
+            let mut report = Report::new(Duration::from_millis(budget));
+            let mut skipped = Vec::new();
+            for day in days::DAYS {
+                let timings = InputSource::Cached
+                    .read(&InputCache::default(), day.day())
+                    .map(|input| bench::bench(*day, &input, iterations));
+                match timings {
+                    Ok(Ok(timings)) => report.add(day.title(), timings),
+                    Ok(Err(err)) => report.fail(day.day(), &err),
+                    Err(err) if is_not_found(&err) => skipped.push(day.day().to_string()),
+                    Err(err) => report.fail(day.day(), &err),
+                }
+            }
+
+            println!("{report}");
+            if !skipped.is_empty() {
+                println!("Skipped days without an input: {}", skipped.join(", "));
+            }
+            if report.has_failures() {
+                bail!("Some days couldn't be timed");
+            }
+            if report.over_budget() {
+                bail!("The season took longer than the {budget}ms budget");
+            }
+        }
+        Command::New { day, title, fetch } => new_day(day, title, fetch)?,
+    }
+
+    Ok(())
+}
+
+/// Whether reading an input failed only because there isn't one yet.
+fn is_not_found(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<io::Error>().is_some_and(|e| e.kind() == ErrorKind::NotFound)
+}
+
+fn select_days(days: Vec<RangeInclusive<u32>>, all: bool) -> Result<Vec<&'static dyn DynSolution>> {
+    if all {
+        return Ok(days::DAYS.to_vec());
+    }
+
+    days.into_iter()
+        .flatten()
+        .map(|number| days::get(number).ok_or_else(|| anyhow!("Day {number} has not been solved yet")))
+        .collect()
+}
+
+/// One day to run on one input, checked against the answers of the profile the input belongs to.
+struct Job {
+    day: &'static dyn DynSolution,
+    source: InputSource,
+    /// Index of the profile in the ones loaded for this run.
+    profile: usize,
+}
+
+/// What running a day produced, kept until it's that day's turn to be printed.
+struct DayRun {
+    parse_time: Duration,
+    parts: [Result<(String, Duration)>; 2],
+}
+
+fn solve_day(day: &dyn DynSolution, source: &InputSource, cache: &InputCache) -> Result<DayRun> {
+    let input = source.read(cache, day.day())?;
+
+    let start = Instant::now();
+    let parsed = day.parse(&input).with_context(|| format!("Failed to parse {}", source.describe(cache, day.day())))?;
+    let parse_time = start.elapsed();
+
+    let timed = |part: fn(&dyn DynSolution, &dyn Any) -> Result<String>| {
+        let start = Instant::now();
+        part(day, parsed.as_ref()).map(|answer| (answer, start.elapsed()))
+    };
+    let parts = [timed(|day, input| day.part1(input)), timed(|day, input| day.part2(input))];
+
+    Ok(DayRun { parse_time, parts })
+}
+
+fn print_day(job: &Job, profile: &Profile, run: Result<DayRun>, store: &mut AnswerStore, accept: bool) -> Result<[Verdict; 2]> {
+    let Job { day, source, .. } = job;
+    start_day(&day.day().to_string());
+    println!("--- {} ---", day.title());
+    if !profile.is_default() {
+        println!("Input: {} ({})", source.describe(&profile.cache(), day.day()), profile.name);
+    } else if !source.is_cached() {
+        println!("Input: {}", source.describe(&profile.cache(), day.day()));
+    }
+
+    let run = run?;
+    println!("Parsed in {:.2?}", run.parse_time);
+
+    let mut verdicts = [Verdict::New, Verdict::New];
+    for (part, result) in (1..=2).zip(run.parts) {
+        println!("{}=== Part {part} ===", if part == 1 { "" } else { "\n" });
+        let (answer, time) = result?;
+        println!("Solved in {time:.2?}");
+        if source.is_cached() {
+            verdicts[part as usize - 1] = check_answer(store, day.day(), part, &answer, accept);
+        } else {
+            // The answers store only knows the answers for the cached input.
+            println!("Result = {answer}");
+        }
+    }
+
+    Ok(verdicts)
+}
+
+fn check_answer(store: &mut AnswerStore, day: u32, part: u8, answer: &str, accept: bool) -> Verdict {
+    let verdict = store.check(day, part, answer);
+    println!("Result = {} ({})", answer, verdict);
+
+    if accept && verdict == Verdict::New {
+        store.record(day, part, answer);
+    }
+
+    verdict
+}
+
+fn new_day(day: u32, title: Option<String>, fetch: bool) -> Result<()> {
+    let scaffold = Scaffold::new(".");
+    if scaffold.day_path(day).exists() {
+        bail!("{} already exists, refusing to overwrite it", scaffold.day_path(day).display());
+    }
+
+    let title = if fetch {
+        let fetched = tokio::runtime::Runtime::new()?.block_on(fetch_day(day))?;
+        title.or(fetched)
+    } else {
+        title
+    };
+
+    let path = scaffold.create_day(day, title.as_deref().unwrap_or(TITLE_PLACEHOLDER))?;
+    println!("Created {} and registered it in src/days/mod.rs", path.display());
+    if title.is_none() {
+        println!("Remember to replace {TITLE_PLACEHOLDER} with the puzzle title");
+    }
+
+    Ok(())
+}
+
+/// Downloads the input, description and example for `day`, returning the puzzle title.
+async fn fetch_day(day: u32) -> Result<Option<String>> {
+    let mut config = Config::load(CONFIG_FILE)?;
+    config.apply_env()?;
+    if let Some(remaining) = Schedule::new(SystemClock, config.year).time_until_unlock(day)? {
+        bail!("Day {day} unlocks in {}, try again then", schedule::format_wait(remaining));
+    }
+    let profile = config.profile(Profile::DEFAULT)?;
+    let client = AocClient::for_profile(&config, &profile)?;
+
+    let cache = profile.cache();
+    if !cache.is_cached(day) {
+        cache.store(day, &client.download_input(day).await?)?;
+        println!("Saved the input to {}", cache.path(day).display());
+    }
+
+    let page = client.fetch_puzzle(day).await?;
+    println!("Saved the puzzle description to {}", puzzle::save_description(&page, day)?.display());
+    if let Some(example) = puzzle::save_example(&page, day)? {
+        println!("Saved the first code block to {}, check it is the example", example.display());
+    }
+
+    Ok(puzzle::title(&page))
+}
+
+fn parse_days(spec: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse = |num: &str| num.trim().parse::<u32>().map_err(|_| format!("`{num}` is not a valid day"));
+
+    let range = if let Some((start, end)) = spec.split_once("..=") {
+        parse(start)?..=parse(end)?
+    } else if let Some((start, end)) = spec.split_once("..") {
+        parse(start)?..=parse(end)?.saturating_sub(1)
+    } else {
+        let day = parse(spec)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("`{spec}` does not contain any days"));
+    }
+
+    Ok(range)
+}
@@ -1,61 +1,255 @@
-use std::{fs::{self, read}, io::ErrorKind};
+use std::path::PathBuf;
 
-use anyhow::{Context, Result};
-use chrono::{Datelike, Utc};
-use clap::Parser;
-use reqwest::ClientBuilder;
+use adv_code_2024::answers::AnswerStore;
+use adv_code_2024::cache::InputCache;
+use adv_code_2024::client::{AocClient, SubmitOutcome};
+use adv_code_2024::config::{Config, Profile, CONFIG_FILE};
+use adv_code_2024::puzzle;
+use adv_code_2024::schedule::{self, Clock, Schedule, SystemClock, LAST_DAY};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
 struct Args {
-    #[arg(short, long, default_value_t = Utc::now().day())]
-    day: u32,
+    /// Puzzle day, defaulting to today's puzzle in US Eastern time
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=LAST_DAY as i64))]
+    day: Option<u32>,
+
+    /// Event year, overriding `AOC_YEAR` and the config file
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// Website to talk to, overriding `AOC_BASE_URL` and the config file
+    #[arg(long)]
+    base_url: Option<String>,
+
+    /// Config file to read the year, base URL, user agent and profiles from
+    #[arg(long, default_value = CONFIG_FILE)]
+    config: PathBuf,
+
+    /// Account to act as, with its own session token, inputs and answers
+    #[arg(short, long, default_value = Profile::DEFAULT)]
+    profile: String,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download the puzzle input (the default when no command is given)
+    Download {
+        /// Download again even if the input is already cached
+        #[arg(short, long)]
+        force: bool,
+
+        /// Sleep until the puzzle unlocks instead of refusing, defaulting to the next puzzle to unlock
+        #[arg(short, long)]
+        wait: bool,
+
+        /// Download the input for every profile in the config, not just `--profile`
+        #[arg(long)]
+        all_profiles: bool,
+    },
+    /// Submit an answer and record the outcome in the answers store
+    Submit {
+        /// Which part the answer is for
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        answer: String,
+    },
+    /// Save the puzzle description as Markdown and its first code block as a candidate example
+    Puzzle,
+    /// Inspect the cached puzzle inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// List the cached inputs and whether each one looks valid
+    List,
+    /// Check that every cached input is non-empty and not an error page
+    Verify,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    let config = config(&args)?;
+    let schedule = Schedule::new(SystemClock, config.year);
+    let profile = config.profile(&args.profile)?;
+    let cache = profile.cache();
+
+    let download = Command::Download { force: false, wait: false, all_profiles: false };
+    match args.command.as_ref().unwrap_or(&download) {
+        Command::Download { force, wait, all_profiles } => {
+            let day = puzzle_day(&args, &schedule, *wait)?;
+            if *all_profiles && !profile.is_default() {
+                bail!("--all-profiles already includes {}, drop --profile", profile.name);
+            }
+            let profiles = if *all_profiles { config.all_profiles() } else { vec![profile] };
+
+            let mut failed = Vec::new();
+            for profile in profiles {
+                let cache = profile.cache();
+                if !*force && cache.is_cached(day) {
+                    println!("Input for day {day} is already cached for {}, use --force to download it again", profile.name);
+                    continue;
+                }
 
-    if !check_cached_input(args.day) {
-        println!("Downloading input file for {}", args.day);
-        download_input(args.day).await?;
+                wait_for_unlock(&schedule, day, *wait).await?;
+                println!("Downloading input file for {day} as {}", profile.name);
+                let downloaded = match AocClient::for_profile(&config, &profile) {
+                    Ok(client) => download_input(&client, &cache, day).await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = downloaded {
+                    eprintln!("Error: {e:#}");
+                    failed.push(profile.name);
+                }
+            }
+
+            if !failed.is_empty() {
+                bail!("Failed to download the input for {}", failed.join(", "));
+            }
+        }
+        Command::Submit { part, answer } => {
+            let day = puzzle_day(&args, &schedule, false)?;
+            wait_for_unlock(&schedule, day, false).await?;
+            submit_answer(&AocClient::for_profile(&config, &profile)?, &profile, day, *part, answer).await?
+        }
+        Command::Puzzle => {
+            let day = puzzle_day(&args, &schedule, false)?;
+            wait_for_unlock(&schedule, day, false).await?;
+            fetch_puzzle(&AocClient::for_profile(&config, &profile)?, day).await?
+        }
+        Command::Cache { command: CacheCommand::List } => list_cache(&cache)?,
+        Command::Cache { command: CacheCommand::Verify } => verify_cache(&cache)?,
     }
 
     Ok(())
 }
 
-fn check_cached_input(day: u32) -> bool {
-    let file = format!("input/{day}.txt");
-    match read(&file) {
-        Ok(_) => true,
-        Err(e) => e.kind() != ErrorKind::NotFound
+/// Reads the config file, then the environment, then the command line, each overriding the last.
+fn config(args: &Args) -> Result<Config> {
+    let mut config = Config::load(&args.config)?;
+    config.apply_env()?;
+    if let Some(year) = args.year {
+        config.year = year;
+    }
+    if let Some(base_url) = &args.base_url {
+        config.base_url = base_url.clone();
     }
+
+    Ok(config)
 }
 
-async fn download_input(day: u32) -> Result<()> {
-    let url = format!("https://adventofcode.com/2024/day/{day}/input");
-    let session = std::env::var("AOC_SESSION").context("Failed to find AOC session token")?;
-    let client = ClientBuilder::new()
-        .user_agent("https://github.com/EthanPlant/AOC2024 by plant.ethan@gmail.com")
-        .build()
-        .context("Failed to build http client")?;
+/// The day given with `--day`, otherwise today's puzzle, or the next one to unlock when waiting.
+fn puzzle_day(args: &Args, schedule: &Schedule<impl Clock>, wait: bool) -> Result<u32> {
+    if let Some(day) = args.day.or_else(|| schedule.current_day()) {
+        return Ok(day);
+    }
+    if wait {
+        return schedule.next_day().context("Every puzzle has already unlocked, pass --day to pick one");
+    }
 
-    let request = client
-        .get(url)
-        .header("Cookie", format!("session={session}"))
-        .build()
-        .context("Failed to build request")?;
+    bail!("No puzzle unlocks today, pass --day to pick one or --wait for the next one")
+}
 
-    let resp = client
-        .execute(request)
-        .await
-        .context("Failed to execute http request")?
-        .error_for_status()
-        .context("Server returned error")?
-        .text()
-        .await
-        .context("Failed to read http response body")?;
+/// Makes sure `day` has unlocked before talking to the website, sleeping until it does if `wait` is set.
+async fn wait_for_unlock(schedule: &Schedule<impl Clock>, day: u32, wait: bool) -> Result<()> {
+    let Some(remaining) = schedule.time_until_unlock(day)? else {
+        return Ok(());
+    };
+
+    let unlock = schedule.unlock_time(day)?;
+    if !wait {
+        bail!("Day {day} unlocks in {} (at {unlock}), pass --wait to wait for it", schedule::format_wait(remaining));
+    }
+
+    println!("Waiting {} for day {day} to unlock at {unlock}", schedule::format_wait(remaining));
+    // A little slack, since the website can lag a moment behind the unlock time.
+    tokio::time::sleep(remaining + std::time::Duration::from_secs(2)).await;
+    Ok(())
+}
+
+async fn download_input(client: &AocClient, cache: &InputCache, day: u32) -> Result<()> {
+    let resp = client.download_input(day).await?;
 
     println!("{}", resp);
-    fs::write(format!("input/{day}.txt"), resp).context("Failed to write input to file")?;
+    cache.store(day, &resp)?;
+    println!("Saved to {}", cache.path(day).display());
+    Ok(())
+}
+
+async fn fetch_puzzle(client: &AocClient, day: u32) -> Result<()> {
+    let page = client.fetch_puzzle(day).await?;
+    let description = puzzle::save_description(&page, day)?;
+    println!("Saved the puzzle description to {}", description.display());
+
+    match puzzle::save_example(&page, day)? {
+        Some(example) => println!(
+            "Saved the first code block to {}, check it is the example and add it to examples/examples.toml",
+            example.display()
+        ),
+        None => println!("No new example saved, either the page has no code blocks or the fixture already exists"),
+    }
+
+    Ok(())
+}
+
+fn list_cache(cache: &InputCache) -> Result<()> {
+    let days = cache.list()?;
+    if days.is_empty() {
+        println!("No inputs cached");
+    }
+
+    for day in days {
+        match cache.verify(day) {
+            Ok(()) => println!("Day {day:>2}: {}", cache.path(day).display()),
+            Err(e) => println!("Day {day:>2}: {} (invalid: {e:#})", cache.path(day).display()),
+        }
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
+
+fn verify_cache(cache: &InputCache) -> Result<()> {
+    let mut invalid = 0;
+    for day in cache.list()? {
+        if let Err(e) = cache.verify(day) {
+            eprintln!("Day {day}: {e:#}");
+            invalid += 1;
+        }
+    }
+
+    if invalid > 0 {
+        bail!("{invalid} cached input(s) are invalid, re-download them with `download --force`");
+    }
+
+    println!("All cached inputs look valid");
+    Ok(())
+}
+
+async fn submit_answer(client: &AocClient, profile: &Profile, day: u32, part: u8, answer: &str) -> Result<()> {
+    let mut store = AnswerStore::load(&profile.answers)?;
+    if let Some(reason) = store.known_outcome(day, part, answer) {
+        bail!("Not submitting day {day} part {part}: {reason}");
+    }
+
+    println!("Submitting {answer} for day {day} part {part}");
+    let outcome = client.submit(day, part, answer).await?;
+    println!("{outcome}");
+
+    match outcome {
+        SubmitOutcome::Correct => store.record(day, part, answer),
+        SubmitOutcome::Wrong(hint) => store.record_wrong(day, part, answer, hint),
+        _ => return Ok(()),
+    }
+
+    store.save()
+}
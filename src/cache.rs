@@ -0,0 +1,175 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+/// Default directory puzzle inputs are cached in, relative to the repository root.
+pub const INPUT_DIR: &str = "input";
+
+/// Puzzle inputs stored as `NN.txt` files, the names the runner reads them from.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl Default for InputCache {
+    fn default() -> Self {
+        Self::new(INPUT_DIR)
+    }
+}
+
+impl InputCache {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self { dir: dir.as_ref().to_path_buf() }
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("{day:02}.txt"))
+    }
+
+    /// A named alternate input for `day`, such as `06.alice.txt` for someone else's input.
+    pub fn alternate_path(&self, day: u32, name: &str) -> PathBuf {
+        self.dir.join(format!("{day:02}.{name}.txt"))
+    }
+
+    pub fn is_cached(&self, day: u32) -> bool {
+        self.path(day).is_file()
+    }
+
+    /// Days that have a cached input, in order.
+    pub fn list(&self) -> Result<Vec<u32>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", self.dir.display())),
+        };
+
+        let mut days = Vec::new();
+        for entry in entries {
+            let name = entry?.file_name();
+            let day = name
+                .to_str()
+                .and_then(|name| name.strip_suffix(".txt"))
+                .filter(|stem| stem.len() == 2)
+                .and_then(|stem| stem.parse().ok());
+            if let Some(day) = day {
+                days.push(day);
+            }
+        }
+        days.sort_unstable();
+
+        Ok(days)
+    }
+
+    /// Every input for `day`: `NN.txt` first if there is one, then the alternates by name.
+    pub fn inputs(&self, day: u32) -> Result<Vec<PathBuf>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", self.dir.display())),
+        };
+
+        let prefix = format!("{day:02}.");
+        let mut alternates = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let is_alternate = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix(&prefix)?.strip_suffix(".txt"))
+                .is_some_and(|name| !name.is_empty());
+            if is_alternate {
+                alternates.push(path);
+            }
+        }
+        alternates.sort();
+
+        let mut inputs: Vec<_> = self.is_cached(day).then(|| self.path(day)).into_iter().collect();
+        inputs.extend(alternates);
+        Ok(inputs)
+    }
+
+    /// Checks the cached input for `day`, returning why it is unusable if it is.
+    pub fn verify(&self, day: u32) -> Result<()> {
+        let path = self.path(day);
+        let contents = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        validate(&contents)
+    }
+
+    /// Validates `contents` and writes it to the cache, replacing any existing input for `day`.
+    pub fn store(&self, day: u32, contents: &str) -> Result<()> {
+        validate(contents).with_context(|| format!("Refusing to cache the input for day {day}"))?;
+
+        fs::create_dir_all(&self.dir).with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let path = self.path(day);
+        fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Rejects contents that can't be a puzzle input: empty files and HTML pages served in place of one.
+pub fn validate(contents: &str) -> Result<()> {
+    let start = contents.trim_start();
+    if start.is_empty() {
+        bail!("Input is empty");
+    }
+
+    let lower = start.get(..start.len().min(64)).unwrap_or(start).to_ascii_lowercase();
+    if lower.starts_with("<!doctype") || lower.starts_with("<html") || lower.starts_with("<head") || lower.starts_with("<body") {
+        bail!("Input looks like an HTML page, not a puzzle input");
+    }
+    if contents.contains("Please don't repeatedly request this endpoint") || contents.contains("Puzzle inputs differ by user") {
+        bail!("Input is an error message from the website: {}", contents.trim());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> InputCache {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        InputCache::new(dir)
+    }
+
+    #[test]
+    fn validation() {
+        assert!(validate("3   4\n4   3\n").is_ok());
+        assert!(validate("").is_err());
+        assert!(validate(" \n\n").is_err());
+        assert!(validate("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(validate("<html><body>500 Internal Server Error</body></html>").is_err());
+        assert!(validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n").is_err());
+    }
+
+    #[test]
+    fn store_and_list() {
+        let cache = temp_cache("store-and-list");
+        assert!(cache.list().unwrap().is_empty());
+
+        cache.store(6, "....#.....\n").unwrap();
+        cache.store(12, "AAAA\n").unwrap();
+        assert!(cache.store(13, "<!DOCTYPE html>").is_err());
+        fs::write(cache.dir.join("06.alt.txt"), "ignored").unwrap();
+
+        assert_eq!(vec![6, 12], cache.list().unwrap());
+        assert!(cache.path(6).ends_with("06.txt"));
+        assert!(cache.alternate_path(6, "alt").ends_with("06.alt.txt"));
+        fs::write(cache.alternate_path(6, "alice"), "#\n").unwrap();
+        assert_eq!(
+            vec![cache.path(6), cache.alternate_path(6, "alice"), cache.alternate_path(6, "alt")],
+            cache.inputs(6).unwrap()
+        );
+        assert!(cache.inputs(7).unwrap().is_empty());
+        assert!(cache.is_cached(6));
+        assert!(!cache.is_cached(13));
+        assert!(cache.verify(12).is_ok());
+
+        fs::write(cache.path(12), "").unwrap();
+        assert!(cache.verify(12).is_err());
+
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+}
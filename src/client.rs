@@ -0,0 +1,205 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use reqwest::{Client, ClientBuilder};
+
+use crate::config::{Config, Profile};
+
+/// HTTP client for the Advent of Code website, authenticated with a session cookie.
+pub struct AocClient {
+    client: Client,
+    base_url: String,
+    year: u32,
+    session: String,
+}
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong(Option<Hint>),
+    RateLimited(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+/// Direction hint given alongside a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::Wrong(None) => write!(f, "That's not the right answer"),
+            SubmitOutcome::Wrong(Some(Hint::TooHigh)) => write!(f, "That's not the right answer, it's too high"),
+            SubmitOutcome::Wrong(Some(Hint::TooLow)) => write!(f, "That's not the right answer, it's too low"),
+            SubmitOutcome::RateLimited(wait) => write!(f, "Answered too recently, wait {}s before trying again", wait.as_secs()),
+            SubmitOutcome::AlreadySolved => write!(f, "This part has already been solved"),
+            SubmitOutcome::Unknown(message) => write!(f, "Unrecognised response: {message}"),
+        }
+    }
+}
+
+impl AocClient {
+    /// Creates a client for `config`, authenticated as `profile`.
+    pub fn for_profile(config: &Config, profile: &Profile) -> Result<Self> {
+        Self::new(config, &profile.session()?)
+    }
+
+    /// Creates a client for the event and website described by `config`.
+    pub fn new(config: &Config, session: &str) -> Result<Self> {
+        let client = ClientBuilder::new()
+            .user_agent(&config.user_agent)
+            .build()
+            .context("Failed to build http client")?;
+
+        Ok(Self {
+            client,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            year: config.year,
+            session: session.to_string(),
+        })
+    }
+
+    pub async fn download_input(&self, day: u32) -> Result<String> {
+        self.get(&format!("day/{day}/input")).await
+    }
+
+    /// Fetches the puzzle page for `day`, which includes part 2 once part 1 has been solved.
+    pub async fn fetch_puzzle(&self, day: u32) -> Result<String> {
+        self.get(&format!("day/{day}")).await
+    }
+
+    async fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}/{}/{path}", self.base_url, self.year);
+        let request = self
+            .client
+            .get(url)
+            .header("Cookie", format!("session={}", self.session))
+            .build()
+            .context("Failed to build request")?;
+
+        self.client
+            .execute(request)
+            .await
+            .context("Failed to execute http request")?
+            .error_for_status()
+            .context("Server returned error")?
+            .text()
+            .await
+            .context("Failed to read http response body")
+    }
+
+    pub async fn submit(&self, day: u32, part: u8, answer: &str) -> Result<SubmitOutcome> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let level = part.to_string();
+        let request = self
+            .client
+            .post(url)
+            .header("Cookie", format!("session={}", self.session))
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .build()
+            .context("Failed to build request")?;
+
+        let page = self
+            .client
+            .execute(request)
+            .await
+            .context("Failed to execute http request")?
+            .error_for_status()
+            .context("Server returned error")?
+            .text()
+            .await
+            .context("Failed to read http response body")?;
+
+        Ok(parse_submit_response(&page))
+    }
+}
+
+/// Works out the outcome of a submission from the page the website responds with.
+pub fn parse_submit_response(page: &str) -> SubmitOutcome {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let message = article
+        .captures(page)
+        .map_or(page, |cap| cap.get(1).unwrap().as_str());
+
+    if message.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        SubmitOutcome::Wrong(hint)
+    } else if message.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait(message))
+    } else if message.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        let tags = Regex::new(r"<[^>]*>").unwrap();
+        SubmitOutcome::Unknown(tags.replace_all(message, "").trim().to_string())
+    }
+}
+
+/// Reads the "You have 1m 23s left to wait" part of a rate limit message.
+fn parse_wait(message: &str) -> Duration {
+    let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let seconds = wait.captures(message).map_or(0, |cap| {
+        let minutes: u64 = cap.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = cap.get(2).unwrap().as_str().parse().unwrap();
+        minutes * 60 + seconds
+    });
+
+    Duration::from_secs(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn correct() {
+        let page = response_page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(SubmitOutcome::Correct, parse_submit_response(&page));
+    }
+
+    #[test]
+    fn wrong_with_hint() {
+        let page = response_page("That's not the right answer; your answer is too high.  If you're stuck, ...");
+        assert_eq!(SubmitOutcome::Wrong(Some(Hint::TooHigh)), parse_submit_response(&page));
+
+        let page = response_page("That's not the right answer; your answer is too low.");
+        assert_eq!(SubmitOutcome::Wrong(Some(Hint::TooLow)), parse_submit_response(&page));
+
+        let page = response_page("That's not the right answer.  If you're stuck, ...");
+        assert_eq!(SubmitOutcome::Wrong(None), parse_submit_response(&page));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let page = response_page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        assert_eq!(SubmitOutcome::RateLimited(Duration::from_secs(65)), parse_submit_response(&page));
+
+        let page = response_page("You gave an answer too recently.  You have 42s left to wait.");
+        assert_eq!(SubmitOutcome::RateLimited(Duration::from_secs(42)), parse_submit_response(&page));
+    }
+
+    #[test]
+    fn already_solved() {
+        let page = response_page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(SubmitOutcome::AlreadySolved, parse_submit_response(&page));
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use serde::Deserialize;
+
+use crate::answers::ANSWERS_FILE;
+use crate::cache::{InputCache, INPUT_DIR};
+
+/// Default location of the config file, relative to the repository root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Which event to talk to and how, read from `aoc.toml` and the `AOC_*` environment variables.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: u32,
+    /// Root of the Advent of Code website, or of a mirror serving the same paths.
+    pub base_url: String,
+    pub user_agent: String,
+    /// Time allowed for running every day once, in milliseconds, checked by `aoc report`.
+    pub budget_ms: u64,
+    /// Other accounts to cross-check solutions against, as `[profiles.<name>]` tables.
+    pub profiles: BTreeMap<String, ProfileSettings>,
+}
+
+/// Overrides for where a profile keeps its things, each defaulting to a location derived from its name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProfileSettings {
+    /// Environment variable holding the session cookie, `AOC_SESSION_<NAME>` if unset.
+    pub session_env: Option<String>,
+    /// Directory the inputs are cached in, `input/<name>` if unset.
+    pub input_dir: Option<PathBuf>,
+    /// Answers store, `answers/<name>.toml` if unset.
+    pub answers: Option<PathBuf>,
+}
+
+/// One account: the session cookie to download with, and where its inputs and answers live.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub session_env: String,
+    pub input_dir: PathBuf,
+    pub answers: PathBuf,
+}
+
+impl Profile {
+    /// Name of the account using `AOC_SESSION`, `input/` and `answers.toml`.
+    pub const DEFAULT: &'static str = "default";
+
+    fn new(name: &str, settings: &ProfileSettings) -> Self {
+        Self {
+            name: name.to_string(),
+            session_env: settings.session_env.clone().unwrap_or_else(|| format!("AOC_SESSION_{}", name.to_uppercase())),
+            input_dir: settings.input_dir.clone().unwrap_or_else(|| Path::new(INPUT_DIR).join(name)),
+            answers: settings.answers.clone().unwrap_or_else(|| Path::new("answers").join(format!("{name}.toml"))),
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.name == Self::DEFAULT
+    }
+
+    pub fn cache(&self) -> InputCache {
+        InputCache::new(&self.input_dir)
+    }
+
+    /// The session cookie from this profile's environment variable.
+    pub fn session(&self) -> Result<String> {
+        std::env::var(&self.session_env)
+            .with_context(|| format!("Failed to find the session token for profile {} in {}", self.name, self.session_env))
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            name: Self::DEFAULT.to_string(),
+            session_env: "AOC_SESSION".to_string(),
+            input_dir: PathBuf::from(INPUT_DIR),
+            answers: PathBuf::from(ANSWERS_FILE),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: 2024,
+            base_url: "https://adventofcode.com".to_string(),
+            user_agent: "https://github.com/EthanPlant/AOC2024 by plant.ethan@gmail.com".to_string(),
+            budget_ms: 1000,
+            profiles: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config at `path`, falling back to the defaults if the file doesn't exist.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let config: Self = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        ensure!(
+            !config.profiles.contains_key(Profile::DEFAULT),
+            "{} can't have a [profiles.{}] table, that name is reserved for AOC_SESSION, input/ and answers.toml",
+            path.display(),
+            Profile::DEFAULT
+        );
+        Ok(config)
+    }
+
+    /// The profile called `name`, where `default` is the account the rest of the tools use.
+    pub fn profile(&self, name: &str) -> Result<Profile> {
+        if name == Profile::DEFAULT {
+            return Ok(Profile::default());
+        }
+
+        let settings = self.profiles.get(name).ok_or_else(|| {
+            let known: Vec<_> = self.profiles.keys().map(String::as_str).collect();
+            anyhow!("No profile called {name} in the config, known profiles: {}", known.join(", "))
+        })?;
+        Ok(Profile::new(name, settings))
+    }
+
+    /// The default profile followed by every configured one.
+    pub fn all_profiles(&self) -> Vec<Profile> {
+        let named = self.profiles.iter().map(|(name, settings)| Profile::new(name, settings));
+        std::iter::once(Profile::default()).chain(named).collect()
+    }
+
+    /// Overrides settings with `AOC_YEAR`, `AOC_BASE_URL` and `AOC_USER_AGENT` where they are set.
+    pub fn apply_env(&mut self) -> Result<()> {
+        self.apply_vars(|name| std::env::var(name).ok())
+    }
+
+    fn apply_vars(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<()> {
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = year.trim().parse().with_context(|| format!("AOC_YEAR `{year}` is not a valid year"))?;
+        }
+        if let Some(base_url) = var("AOC_BASE_URL") {
+            self.base_url = base_url;
+        }
+        if let Some(user_agent) = var("AOC_USER_AGENT") {
+            self.user_agent = user_agent;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_file() {
+        let config: Config = toml::from_str("year = 2023\n").unwrap();
+        assert_eq!(2023, config.year);
+        assert_eq!(1000, config.budget_ms);
+        assert_eq!(Config::default().base_url, config.base_url);
+
+        assert!(toml::from_str::<Config>("yaer = 2023\n").is_err());
+    }
+
+    #[test]
+    fn missing_file() {
+        let config = Config::load("does/not/exist/aoc.toml").unwrap();
+        assert_eq!(Config::default(), config);
+    }
+
+    #[test]
+    fn profiles() {
+        let config: Config = toml::from_str(
+            "[profiles.alice]\n\n[profiles.bob]\nsession_env = \"BOB_COOKIE\"\ninput_dir = \"../bob/input\"\n",
+        )
+        .unwrap();
+
+        let alice = config.profile("alice").unwrap();
+        assert_eq!("AOC_SESSION_ALICE", alice.session_env);
+        assert_eq!(Path::new("input/alice"), alice.input_dir);
+        assert_eq!(Path::new("answers/alice.toml"), alice.answers);
+
+        let bob = config.profile("bob").unwrap();
+        assert_eq!("BOB_COOKIE", bob.session_env);
+        assert_eq!(Path::new("../bob/input"), bob.input_dir);
+
+        assert_eq!(Profile::default(), config.profile("default").unwrap());
+        assert!(config.profile("carol").unwrap_err().to_string().contains("alice, bob"));
+        let names: Vec<_> = config.all_profiles().into_iter().map(|profile| profile.name).collect();
+        assert_eq!(vec!["default", "alice", "bob"], names);
+    }
+
+    #[test]
+    fn reserved_profile_name() {
+        let path = std::env::temp_dir().join(format!("aoc-config-{}.toml", std::process::id()));
+        fs::write(&path, "[profiles.default]\nsession_env = \"MY_SESSION\"\n").unwrap();
+        assert!(Config::load(&path).unwrap_err().to_string().contains("reserved"));
+
+        fs::write(&path, "[profiles.alice]\n").unwrap();
+        assert_eq!(2, Config::load(&path).unwrap().all_profiles().len());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn env_overrides() {
+        let mut config = Config::default();
+        config
+            .apply_vars(|name| match name {
+                "AOC_YEAR" => Some("2022".to_string()),
+                "AOC_BASE_URL" => Some("http://localhost:8080".to_string()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(2022, config.year);
+        assert_eq!("http://localhost:8080", config.base_url);
+        assert_eq!(Config::default().user_agent, config.user_agent);
+
+        assert!(config.apply_vars(|_| Some("twenty".to_string())).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::*;
+use itertools::Itertools;
+use std::fmt::Display;
+use std::io::BufRead;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day01;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<i32>> {
+    let mut numbers = Vec::new();
+    for line in parse::lines(&parse::read_input(reader)?) {
+        numbers.extend(line.ints_n::<i32, 2>()?);
+    }
+
+    Ok(numbers)
+}
+
+fn split_columns(numbers: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+    numbers
+        .into_iter()
+        .enumerate()
+        .partition_map(|(i, val)| {
+            match i % 2 {
+                0 => itertools::Either::Left(val),
+                1 => itertools::Either::Right(val),
+                _ => unreachable!()
+            }
+        })
+    }
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let numbers = parse_input(reader)?;
+
+        let (mut left, mut right) = split_columns(numbers);
+
+        left.sort();
+        right.sort();
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<impl Display> {
+        let result = left
+            .iter()
+            .zip(right.iter())
+            .map(|(l, r)| (l - r).abs())
+            .sum::<i32>();
+
+        Ok(result as usize)
+    }
+
+    fn part2((left, right): &Self::Input) -> Result<impl Display> {
+        let result: usize = left
+            .iter()
+            .map(|&l| (l as usize) * right.iter().filter(|&&x| x == l).count())
+            .sum();
+
+        Ok(result)
+    }
+}
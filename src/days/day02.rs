@@ -0,0 +1,59 @@
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day02;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Vec<i32>>> {
+    parse::lines(&parse::read_input(reader)?).map(|line| line.words()).collect()
+}
+
+fn is_report_safe(report: &[i32]) -> bool {
+    let ascending = report.is_sorted_by(|a, b| {a < b && a.abs_diff(*b) <= 3});
+    let descending = report.is_sorted_by(|a, b| {a > b && a.abs_diff(*b) <= 3});
+    ascending || descending
+}
+
+fn is_report_safe_with_removal(report: &[i32]) -> bool {
+    if is_report_safe(report) {
+        return true
+    }
+
+    report
+        .iter()
+        .cloned()
+        .combinations(report.len() - 1)
+        .any(|combination| is_report_safe(&combination))
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<impl Display> {
+        Ok(
+            reports
+                .iter()
+                .filter(|report| is_report_safe(report))
+                .count()
+        )
+    }
+
+    fn part2(reports: &Self::Input) -> Result<impl Display> {
+        Ok(
+            reports
+                .iter()
+                .filter(|report| is_report_safe_with_removal(report))
+                .count()
+        )
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::*;
+use regex::Regex;
+use std::io::BufRead;
+use std::sync::LazyLock;
+use std::fmt::Display;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day03;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+static INSTRUCTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").expect("The instruction pattern is valid"));
+
+/// The instructions hidden in the corrupted memory, in order across every line.
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Instruction>> {
+    let input = parse::read_input(reader)?;
+    let mut instructions = Vec::new();
+    for line in parse::lines(&input) {
+        for cap in INSTRUCTION.captures_iter(line.text) {
+            instructions.push(match &cap[0] {
+                "do()" => Instruction::Do,
+                "don't()" => Instruction::Dont,
+                _ => Instruction::Mul(line.parse_part(&cap[1])?, line.parse_part(&cap[2])?),
+            });
+        }
+    }
+
+    Ok(instructions)
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Instruction>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<impl Display> {
+        Ok(instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(a, b) => a * b,
+                _ => 0,
+            })
+            .sum::<usize>()
+        )
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<impl Display> {
+        let mut do_mul = true;
+        let mut ans: usize = 0;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Do => do_mul = true,
+                Instruction::Dont => do_mul = false,
+                Instruction::Mul(a, b) => {
+                    if do_mul {
+                        ans += a * b;
+                    }
+                }
+            }
+        }
+
+        Ok(ans)
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::grid::{Compass, Grid, Vector};
+use crate::parse;
+use crate::Solution;
+
+pub struct Day04;
+
+fn test_word(grid: &Grid<char>, pos: Vector) -> usize {
+    let chars = ['X', 'M', 'A', 'S'];
+
+    if grid[pos] != chars[0] {
+        return 0;
+    }
+
+    Compass::iter()
+        .filter(|&dir| {
+            let mut curr = pos;
+            chars[1..].iter().all(|c| {
+                curr += dir.into();
+                grid.get(curr) == Some(c)
+            })
+        })
+        .count()
+}
+
+fn check_xmas(grid: &Grid<char>, pos: Vector) -> bool {
+    if grid[pos] != 'A' {
+        return false;
+    }
+
+    let is_mas = |dir: Compass| {
+        matches!(
+            (grid.get(pos + dir.into()), grid.get(pos + dir.opposite().into())),
+            (Some('M'), Some('S')) | (Some('S'), Some('M'))
+        )
+    };
+
+    is_mas(Compass::NW) && is_mas(Compass::NE)
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    parse::grid(&parse::read_input(reader)?)
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<char>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.positions().map(|pos| test_word(input, pos)).sum::<usize>())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(input.positions().filter(|&pos| check_xmas(input, pos)).count())
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::*;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::parse::{self, Section};
+use crate::Solution;
+
+pub struct Day05;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Rule(usize, usize);
+
+fn parse_rules(section: &Section) -> Result<HashSet<Rule>> {
+    section
+        .lines()
+        .iter()
+        .map(|line| {
+            let [before, after] = line
+                .split("|")?
+                .try_into()
+                .map_err(|_| line.error(0, line.text.len(), "expected a rule like `47|53`"))?;
+            Ok(Rule(before, after))
+        })
+        .collect()
+}
+
+fn parse_updates(section: &Section) -> Result<Vec<Vec<usize>>> {
+    section.lines().iter().map(|line| line.split(",")).collect()
+}
+
+fn check_update(update: &[usize], rules: &HashSet<Rule>) -> bool {
+    update.is_sorted_by(|a, b| !rules.contains(&Rule(*b, *a)))
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (HashSet<Rule>, Vec<Vec<usize>>);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = parse::read_input(reader)?;
+        let [rules, updates] = parse::sections_n(&input)?;
+
+        Ok((parse_rules(&rules)?, parse_updates(&updates)?))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Result<impl Display> {
+        Ok(
+            updates
+                .iter()
+                .filter(|update| check_update(update, rules))
+                .map(|update| update[update.len() / 2])
+                .sum::<usize>()
+        )
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<impl Display> {
+        let invalid: Vec<Vec<usize>> = updates
+            .iter()
+            .filter(|update| !check_update(update, rules))
+            .cloned()
+            .collect();
+
+        let fixed: Vec<Vec<usize>> = invalid
+            .into_iter()
+            .map(|mut update| {
+                update.sort_by(|a, b| {
+                    if rules.contains(&Rule(*a, *b)) {
+                        Ordering::Less
+                    } else if rules.contains(&Rule(*b, *a)) {
+                        Ordering::Greater
+                    } else {
+                        unreachable!()
+                    }
+                });
+                update
+            })
+            .collect();
+
+        Ok(
+            fixed
+                .into_iter()
+                .map(|update| update[update.len() / 2])
+                .sum::<usize>()
+        )
+    }
+}
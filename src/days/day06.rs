@@ -0,0 +1,165 @@
+use anyhow::*;
+use rustc_hash::FxHashSet;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::grid::{Dir, Grid, Vector};
+use crate::recorder::Recorder;
+use crate::render::{Frame, Rgb};
+use crate::{parallel, parse};
+use crate::Solution;
+
+pub struct Day06;
+
+#[derive(Debug, Clone)]
+pub struct Guard {
+    position: Vector,
+    dir: Dir,
+}
+
+impl Guard {
+    pub fn get_visited_positions(&mut self, map: &Map) -> FxHashSet<Vector> {
+        let mut visited = FxHashSet::default();
+        visited.insert(self.position);
+        while self.is_in_map(map) {
+            self.step(map);
+            if self.is_in_map(map) {
+                visited.insert(self.position);
+            }
+        }
+
+        visited
+    }
+
+    /// Walks the guard until it leaves the map or comes back to a position and direction it has already been in,
+    /// recording the map with its trail before the first step and after each one.
+    pub fn record_walk(&self, map: &Map, recorder: &mut Recorder) {
+        let mut guard = self.clone();
+        let mut visited = vec![guard.position];
+        let mut seen = FxHashSet::default();
+        recorder.record(|| guard.render(map, &visited));
+        while guard.is_in_map(map) && seen.insert((guard.position, guard.dir)) {
+            guard.step(map);
+            if guard.is_in_map(map) {
+                visited.push(guard.position);
+            }
+            recorder.record(|| guard.render(map, &visited));
+        }
+    }
+
+    fn render(&self, map: &Map, visited: &[Vector]) -> Frame {
+        let arrow = match self.dir {
+            Dir::UP => '^',
+            Dir::RIGHT => '>',
+            Dir::DOWN => 'v',
+            Dir::LEFT => '<',
+        };
+
+        let mut frame = Frame::from_grid(&map.obstacles, |&obstacle| if obstacle { '#' } else { '.' });
+        frame
+            .overlay(visited.iter().copied(), Some('X'), Some(Rgb::YELLOW), None)
+            .overlay([self.position], Some(arrow), Some(Rgb::RED), None);
+        frame
+    }
+
+    /// Turns right if an obstacle is in the way, otherwise steps forwards.
+    fn step(&mut self, map: &Map) {
+        let new_pos = self.position + self.dir.into();
+        if map.is_obstacle(new_pos) {
+            self.dir = self.dir.turn_right();
+        } else {
+            self.position = new_pos;
+        }
+    }
+
+    pub fn check_if_loop(&mut self, map: &Map) -> bool {
+        let start_pos = self.position;
+        let mut turns = FxHashSet::default();
+        while self.is_in_map(map) {
+            let new_pos = self.position + self.dir.into();
+            if map.is_obstacle(new_pos) {
+                if turns.contains(&(new_pos, self.dir)) {
+                    self.position = start_pos;
+                    self.dir = Dir::UP;
+                    return true
+                }
+                turns.insert((new_pos, self.dir));
+                self.dir = self.dir.turn_right();
+            } else {
+                self.position = new_pos;
+            }
+        }
+        self.position = start_pos;
+        self.dir = Dir::UP;
+        false
+    }
+
+    fn is_in_map(&self, map: &Map) -> bool {
+        map.obstacles.contains(self.position)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    obstacles: Grid<bool>,
+}
+
+impl Map {
+    fn is_obstacle(&self, pos: Vector) -> bool {
+        self.obstacles.get(pos).is_some_and(|&obstacle| obstacle)
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<(Guard, Map)> {
+    let input: Grid<char> = parse::grid(&parse::read_input(reader)?)?;
+
+    let guard = Guard {
+        position: input.find(&'^').context("Map has no guard")?,
+        dir: Dir::UP,
+    };
+    let map = Map {
+        obstacles: input.map(|&c| c == '#'),
+    };
+
+    Ok((guard, map))
+}
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = (Guard, Map);
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1((guard, map): &Self::Input) -> Result<impl Display> {
+        let mut guard = guard.clone();
+
+        Ok(guard.get_visited_positions(map).len())
+    }
+
+    fn part2((guard, map): &Self::Input) -> Result<impl Display> {
+        let start_pos = guard.position;
+        let candidates: Vec<Vector> = guard
+            .clone()
+            .get_visited_positions(map)
+            .into_iter()
+            .filter(|&position| position != start_pos)
+            .collect();
+
+        // Each candidate obstacle is tried on a thread's own copy of the map.
+        let loops = parallel::map_init(
+            &candidates,
+            || (guard.clone(), map.clone()),
+            |(guard, map), &position| {
+                map.obstacles[position] = true;
+                let looped = guard.check_if_loop(map);
+                map.obstacles[position] = false;
+                looped
+            },
+        );
+
+        Ok(loops.into_iter().filter(|&looped| looped).count())
+    }
+}
@@ -1,27 +1,13 @@
 use anyhow::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use adv_code_2024::*;
-
-const DAY: &str = "07";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
-
-const TEST: &str = "\
-190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20
-";
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::{parallel, parse};
+use crate::Solution;
+
+pub struct Day07;
 
 #[derive(Debug)]
-struct Equation(usize, Vec<usize>);
+pub struct Equation(usize, Vec<usize>);
 
 // fn check_equation_valid(equation: &Vec<usize>) -> bool {
 //     if equation.len() == 2 {
@@ -72,7 +58,7 @@ struct Equation(usize, Vec<usize>);
 //     check_equation_valid_concat(&add_equation) || check_equation_valid_concat(&mul_equation) || check_equation_valid_concat(&concat_equation)
 // }
 
-fn check_equation_valid(target: usize, equation: &Vec<usize>, index: usize, part2: bool) -> bool {
+fn check_equation_valid(target: usize, equation: &[usize], index: usize, part2: bool) -> bool {
     if index == 0 {
         return target == equation[0]
     }
@@ -84,79 +70,63 @@ fn check_equation_valid(target: usize, equation: &Vec<usize>, index: usize, part
     if target >= val {
         add = check_equation_valid(target - val, equation, index - 1, part2);
     }
-    if target % val == 0 {
+    if target.is_multiple_of(val) {
         mul = check_equation_valid(target / val, equation, index - 1, part2);
     }
     if part2 && target >= val{
         let ndigits = val.checked_ilog10().unwrap_or(0) + 1;
         let disconcat: usize = (target - val) / (10usize.pow(val.ilog10() + 1));
-        if target % 10usize.pow(ndigits as u32) == val {
+        if target % 10usize.pow(ndigits) == val {
             concat = check_equation_valid(disconcat, equation, index - 1, part2);
         }
     }
     add || mul || concat
 }
 
-fn parse_input<R: BufRead>(reader: R) -> Vec<Equation> {
-    reader
-        .lines()
-        .flatten()
+/// Sum of the test values of the equations that can be made true, checking each on its own thread.
+fn calibration_result(equations: &[Equation], part2: bool) -> usize {
+    parallel::map(equations, |Equation(target, numbers)| {
+        if check_equation_valid(*target, numbers, numbers.len() - 1, part2) {
+            *target
+        } else {
+            0
+        }
+    })
+    .into_iter()
+    .sum()
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Equation>> {
+    parse::lines(&parse::read_input(reader)?)
         .map(|line| {
-            let (target, rest) = line.split_once(':').unwrap();
-            Equation(
-                target.parse().unwrap(),
-                rest
-                    .split_whitespace()
-                    .map(|num| num.parse().unwrap())
-                    .collect()
-            )
+            let (target, numbers) = line.key_value()?;
+            let numbers: Vec<usize> = numbers
+                .split_whitespace()
+                .map(|num| line.parse_part(num))
+                .collect::<Result<_>>()?;
+            if numbers.is_empty() {
+                return Err(line.error(0, line.text.len(), "expected numbers after the test value"));
+            }
+            Ok(Equation(line.parse_part(target)?, numbers))
         })
         .collect()
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Bridge Repair";
 
-    println!("=== Part 1 ===");
+    type Input = Vec<Equation>;
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-
-        let equations: Vec<Equation> = parse_input(reader);
-        
-        Ok(
-            equations
-                .into_iter()
-                .filter(|equation| check_equation_valid(equation.0, &equation.1, equation.1.len() - 1, false))
-                .map(|equation| equation.0)
-                .sum()
-        )
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
     }
 
-    assert_eq!(3749, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-
-    println!("\n=== Part 2 ===");
-    
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let equations = parse_input(reader);
+    fn part1(equations: &Self::Input) -> Result<impl Display> {
+        Ok(calibration_result(equations, false))
+    }
 
-        Ok(
-            equations
-                .into_iter()
-                .filter(|equation| check_equation_valid(equation.0, &equation.1, equation.1.len() - 1, true))
-                .map(|equation| equation.0)
-                .sum()
-        )
+    fn part2(equations: &Self::Input) -> Result<impl Display> {
+        Ok(calibration_result(equations, true))
     }
-    
-    assert_eq!(11387, part2(BufReader::new(TEST.as_bytes()))?);
-    
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-
-    Ok(())
 }
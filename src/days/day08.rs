@@ -0,0 +1,117 @@
+use anyhow::*;
+use itertools::enumerate;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::grid::{Grid, Vector};
+use crate::parse;
+use crate::Solution;
+
+pub struct Day08;
+
+#[derive(Debug)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    antennas: FxHashMap<char, Vec<Vector>>,
+}
+
+impl Map {
+    pub fn find_antinode(&self, antenna1: Vector, antenna2: Vector, part2: bool) -> Vec<Vector> {
+        let dist = antenna2 - antenna1;
+        let mut antinodes = vec![antenna2 + dist, antenna1 - dist];
+
+        if part2 {
+            let mut pos1 = antenna1 - dist;
+            let mut pos2 = antenna2 + dist;
+
+            while self.is_in_map(pos1) {
+                antinodes.push(pos1);
+                pos1 -= dist;
+            }
+
+            while self.is_in_map(pos2) {
+                antinodes.push(pos2);
+                pos2 += dist;
+
+            }
+        }
+
+        antinodes
+    }
+
+    pub fn is_in_map(&self, pos: Vector) -> bool {
+        <(usize, usize)>::try_from(pos).is_ok_and(|(row, col)| row < self.height && col < self.width)
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Map> {
+    let grid: Grid<char> = parse::grid(&parse::read_input(reader)?)?;
+    let mut antennas: FxHashMap<char, Vec<Vector>> = FxHashMap::default();
+    for (pos, &c) in grid.iter() {
+        if c != '.' {
+            antennas.entry(c).or_default().push(pos);
+        }
+    }
+
+    Ok(Map {
+        width: grid.width(),
+        height: grid.height(),
+        antennas
+    })
+}
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = Map;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display> {
+        let mut antinodes = FxHashSet::default();
+
+        for antennas in map.antennas.values() {
+            for (i, &a1) in enumerate(antennas) {
+                for &a2 in &antennas[i + 1..] {
+                    let anti = map.find_antinode(a1, a2, false);
+                    antinodes.extend(anti);
+                }
+            }
+        }
+
+        Ok(
+            antinodes
+                .into_iter()
+                .filter(|&antinode| map.is_in_map(antinode))
+                .count()
+        )
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display> {
+        let mut antinodes = FxHashSet::default();
+
+        for antennas in map.antennas.values() {
+            if antennas.len() > 1 {
+                antinodes.extend(antennas.clone());
+            }
+
+            for (i, &a1) in enumerate(antennas) {
+                for &a2 in &antennas[i + 1..] {
+                    let anti = map.find_antinode(a1, a2, true);
+                    antinodes.extend(anti);
+                }
+            }
+        }
+
+        Ok(
+            antinodes
+                .into_iter()
+                .filter(|&antinode| map.is_in_map(antinode))
+                .count()
+        )
+    }
+}
@@ -2,16 +2,11 @@ use anyhow::*;
 use core::fmt::Display;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use adv_code_2024::*;
+use std::io::BufRead;
+use crate::parse;
+use crate::Solution;
 
-const DAY: &str = "09";
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
-
-const TEST: &str = "2333133121414131402";
+pub struct Day09;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
 struct FileDescriptor {
@@ -28,11 +23,7 @@ enum Block {
 
 impl Block {
     fn is_empty(&self) -> bool {
-        if let Block::Empty = &self {
-            true
-        } else {
-            false
-        }
+        matches!(self, Block::Empty)
     }
 }
 
@@ -45,7 +36,8 @@ impl Display for Block {
     }
 }
 
-struct Disk {
+#[derive(Clone)]
+pub struct Disk {
     blocks: Vec<Block>,
     files: Vec<FileDescriptor>,
     free_spaces: [BinaryHeap<Reverse<usize>>; 10],
@@ -118,7 +110,7 @@ impl Display for Disk {
     }
 }
 
-fn parse_input<R: BufRead>(reader: R) -> Disk {
+fn parse_input<R: BufRead>(reader: R) -> Result<Disk> {
     let mut blocks = Vec::new();
     let mut files = Vec::new();
     let mut file_index = 0;
@@ -135,10 +127,12 @@ fn parse_input<R: BufRead>(reader: R) -> Disk {
         BinaryHeap::new(),
     ];
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-        for (i, c) in line.chars().enumerate() {
-            let val = c.to_digit(10).unwrap() as usize;
+    for line in parse::lines(&parse::read_input(reader)?) {
+        for (i, (offset, c)) in line.text.char_indices().enumerate() {
+            let val = c
+                .to_digit(10)
+                .ok_or_else(|| line.error(offset, c.len_utf8(), format!("expected a block size, found `{c}`")))?
+                as usize;
             if i % 2 == 0 {
                 for _ in 0..val {
                     blocks.push(Block::Filled(file_index));
@@ -158,45 +152,34 @@ fn parse_input<R: BufRead>(reader: R) -> Disk {
         }
     }
 
-    Disk {
+    Ok(Disk {
         blocks,
         files,
         free_spaces,
-    }
+    })
 }
 
-fn main() -> Result<()> {
-    start_day(DAY);
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
 
-    println!("=== Part 1 ===");
+    type Input = Disk;
 
-    fn part1<R: BufRead>(reader: R) -> Result<usize> {
-        let mut disk = parse_input(reader);
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(disk: &Self::Input) -> Result<impl Display> {
+        let mut disk = disk.clone();
         disk.rearrange();
 
         Ok(disk.calc_checksum())
     }
 
-    assert_eq!(1928, part1(BufReader::new(TEST.as_bytes()))?);
-
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part1(input_file)?);
-    println!("Result = {}", result);
-
-    println!("\n=== Part 2 ===");
-    
-    fn part2<R: BufRead>(reader: R) -> Result<usize> {
-        let mut disk = parse_input(reader);
+    fn part2(disk: &Self::Input) -> Result<impl Display> {
+        let mut disk = disk.clone();
         disk.rearrange_files();
 
         Ok(disk.calc_checksum())
     }
-    
-    assert_eq!(2858, part2(BufReader::new(TEST.as_bytes()))?);
-    
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
-    let result = time_snippet!(part2(input_file)?);
-    println!("Result = {}", result);
-
-    Ok(())
 }
@@ -0,0 +1,63 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::grid::{Grid, Topology, Vector};
+use crate::regions::flood_fill;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day10;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Grid<u32>> {
+    let input = parse::read_input(reader)?;
+    let [map] = parse::sections_n(&input)?;
+    map.try_grid(|c| c.to_digit(10), "a height")
+}
+
+fn is_uphill(from: &u32, to: &u32) -> bool {
+    *to == from + 1
+}
+
+/// Number of peaks reachable from `start`.
+fn score(map: &Grid<u32>, start: Vector) -> usize {
+    flood_fill(map, start, is_uphill)
+        .into_iter()
+        .filter(|&pos| map[pos] == 9)
+        .count()
+}
+
+/// Number of distinct trails from `start` to any peak.
+fn rating(map: &Grid<u32>, start: Vector) -> usize {
+    let mut count = 0;
+    let mut stack = vec![start];
+
+    while let Some(pos) = stack.pop() {
+        if map[pos] == 9 {
+            count += 1;
+            continue;
+        }
+
+        stack.extend(map.neighbors4(pos).filter(|&next| is_uphill(&map[pos], &map[next])));
+    }
+
+    count
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = Grid<u32>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display> {
+        Ok(map.positions_of(&0).map(|start| score(map, start)).sum::<usize>())
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display> {
+        Ok(map.positions_of(&0).map(|start| rating(map, start)).sum::<usize>())
+    }
+}
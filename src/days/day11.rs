@@ -0,0 +1,78 @@
+use anyhow::*;
+use rustc_hash::FxHashMap;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day11;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<usize>> {
+    let input = parse::read_input(reader)?;
+    let line = parse::lines(&input).next().context("Expected a line of stones")?;
+    line.words()
+}
+
+fn blink_once(stone: usize) -> Vec<usize> {
+    if stone == 0 {
+        return vec![1]
+    }
+
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let divisor = 10usize.pow(digits / 2);
+        return vec![stone / divisor, stone % divisor];
+    }
+
+    vec![stone * 2024]
+}
+
+fn blink_all(stones: FxHashMap<usize, usize>) -> FxHashMap<usize, usize> {
+    let mut res = FxHashMap::default();
+
+    for stone in stones {
+        let new_stones = blink_once(stone.0);
+        for new_stone in new_stones {
+            res.entry(new_stone).and_modify(|count| *count += stone.1).or_insert(stone.1);
+        }
+    }
+
+    res
+}
+
+fn count_stones(stones: &[usize], blinks: usize) -> usize {
+    let mut freqs = stones
+        .iter()
+        .copied()
+        .fold(FxHashMap::default(), |mut map, val| {
+            map.entry(val)
+                .and_modify(|freq|*freq += 1)
+                .or_insert(1);
+            map
+        });
+
+    for _ in 0..blinks {
+        freqs = blink_all(freqs);
+    }
+
+    freqs.values().sum()
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Vec<usize>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<impl Display> {
+        Ok(count_stones(stones, 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<impl Display> {
+        Ok(count_stones(stones, 75))
+    }
+}
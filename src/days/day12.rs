@@ -0,0 +1,37 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::grid::Grid;
+use crate::regions::Regions;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day12;
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Grid<char>> {
+    parse::grid(&parse::read_input(reader)?)
+}
+
+/// The garden split into regions of the same plant.
+fn regions(garden: &Grid<char>) -> Regions {
+    Regions::label(garden, |a, b| a == b)
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Grid<char>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(garden: &Self::Input) -> Result<impl Display> {
+        Ok(regions(garden).regions().iter().map(|region| region.area() * region.perimeter()).sum::<usize>())
+    }
+
+    fn part2(garden: &Self::Input) -> Result<impl Display> {
+        Ok(regions(garden).regions().iter().map(|region| region.area() * region.sides()).sum::<usize>())
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::*;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day13;
+
+#[derive(Debug)]
+pub struct Machine {
+    a: (isize, isize),
+    b: (isize, isize),
+    prize: (isize, isize)
+}
+
+fn solve_machine(machine: &Machine, offset: isize) -> isize {
+    let prize = (machine.prize.0 + offset, machine.prize.1 + offset);
+    let det = machine.a.0 * machine.b.1 - machine.a.1 * machine.b.0;
+    let a = (prize.0 * machine.b.1 - prize.1 * machine.b.0) / det;
+    let b = (machine.a.0 * prize.1 - machine.a.1 * prize.0) / det;
+    if (machine.a.0 * a + machine.b.0 * b, machine.a.1 * a + machine.b.1 * b) == (prize.0, prize.1) {
+        a * 3 + b
+    } else {
+        0
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Vec<Machine>> {
+    parse::sections(&parse::read_input(reader)?)
+        .iter()
+        .map(|section| {
+            let [a, b, prize] = section.lines() else {
+                let first = section.lines()[0];
+                return Err(first.error(0, first.text.len(), "expected a machine to be two buttons and a prize"));
+            };
+            let [ax, ay] = a.ints_n()?;
+            let [bx, by] = b.ints_n()?;
+            let [px, py] = prize.ints_n()?;
+            Ok(Machine {
+                a: (ax, ay),
+                b: (bx, by),
+                prize: (px, py),
+            })
+        })
+        .collect()
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Vec<Machine>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<impl Display> {
+        Ok(machines
+            .iter()
+            .map(|machine| solve_machine(machine, 0) as usize)
+            .sum::<usize>()
+        )
+    }
+
+    fn part2(machines: &Self::Input) -> Result<impl Display> {
+        Ok(machines
+            .iter()
+            .map(|machine| solve_machine(machine, 10000000000000) as u128)
+            .sum::<u128>()
+        )
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::*;
+use itertools::Itertools;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::grid::{Torus, Vector};
+use crate::parse;
+use crate::recorder::Recorder;
+use crate::render::Frame;
+use crate::{Params, Solution};
+
+pub struct Day14;
+
+const WIDTH: usize = 101;
+const HEIGHT: usize = 103;
+
+#[derive(Debug, Clone)]
+struct Robot {
+    pos: Vector,
+    vel: Vector,
+}
+
+/// The robots and the wrapping space they patrol.
+#[derive(Debug, Clone)]
+pub struct Restroom {
+    robots: Vec<Robot>,
+    field: Torus,
+}
+
+impl Restroom {
+    fn step(&mut self, steps: i32) {
+        for robot in &mut self.robots {
+            robot.pos = self.field.wrap(robot.pos + robot.vel * steps);
+        }
+    }
+
+    /// The robots' positions as a picture, which is how the Christmas tree in part 2 shows up.
+    pub fn render(&self) -> Frame {
+        Frame::from_points(self.field.width(), self.field.height(), self.robots.iter().map(|robot| robot.pos))
+    }
+
+    /// The robots after each of `steps` seconds, starting from where they are now.
+    pub fn record(&self, steps: usize, recorder: &mut Recorder) {
+        let mut restroom = self.clone();
+        recorder.record(|| restroom.render());
+        for _ in 0..steps {
+            restroom.step(1);
+            recorder.record(|| restroom.render());
+        }
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R, params: &Params) -> Result<Restroom> {
+    let robots = parse::lines(&parse::read_input(reader)?)
+        .map(|line| {
+            let [px, py, vx, vy] = line.ints_n()?;
+            Ok(Robot {
+                pos: Vector(py, px),
+                vel: Vector(vy, vx),
+            })
+        })
+        .collect::<Result<_>>()?;
+    let (width, height) = (params.get_or("width", WIDTH), params.get_or("height", HEIGHT));
+    ensure!(width > 0 && height > 0, "The restroom needs to be at least 1x1, not {width}x{height}");
+
+    Ok(Restroom { robots, field: Torus::new(width, height) })
+}
+
+fn get_safety_factor(restroom: &Restroom) -> usize {
+    let robots = &restroom.robots;
+    let mid_row = restroom.field.height() as i32 / 2;
+    let mid_col = restroom.field.width() as i32 / 2;
+
+    let mut quad_1 = 0;
+    let mut quad_2 = 0;
+    let mut quad_3 = 0;
+    let mut quad_4 = 0;
+
+    for robot in robots {
+        if robot.pos.1 < mid_col && robot.pos.0 < mid_row {
+            quad_1 += 1;
+        } else if robot.pos.1 > mid_col && robot.pos.0 < mid_row {
+            quad_2 += 1;
+        } else if robot.pos.1 < mid_col && robot.pos.0 > mid_row {
+            quad_3 += 1;
+        } else if robot.pos.1 > mid_col && robot.pos.0 > mid_row {
+            quad_4 += 1;
+        }
+    }
+
+    quad_1 * quad_2 * quad_3 * quad_4
+}
+
+fn are_all_unique(robots: &[Robot]) -> bool {
+    robots
+        .iter()
+        .map(|robot| robot.pos)
+        .all_unique()
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = Restroom;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader, &Params::default())
+    }
+
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        parse_input(reader, params)
+    }
+
+    fn part1(restroom: &Self::Input) -> Result<impl Display> {
+        let mut restroom = restroom.clone();
+        restroom.step(100);
+        Ok(get_safety_factor(&restroom))
+    }
+
+    fn part2(restroom: &Self::Input) -> Result<impl Display> {
+        let mut restroom = restroom.clone();
+        // Every robot is back where it started after width * height seconds.
+        for steps in 0..restroom.field.width() * restroom.field.height() {
+            if are_all_unique(&restroom.robots) {
+                return Ok(steps);
+            }
+            restroom.step(1);
+        }
+
+        bail!("The robots never all stand on different tiles")
+    }
+}
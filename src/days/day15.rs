@@ -0,0 +1,251 @@
+use anyhow::*;
+use crate::grid::{Dir, Grid, Vector};
+use crate::recorder::Recorder;
+use crate::render::{Frame, Rgb};
+use std::collections::VecDeque;
+use core::fmt::Display;
+use std::io::BufRead;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day15;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Tile {
+    Empty,
+    Wall,
+    Box,
+    Robot,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Tile {
+    fn double(self) -> impl Iterator<Item = Self> {
+        match self {
+            Tile::Empty => [Tile::Empty, Tile::Empty].into_iter(),
+            Tile::Wall => [Tile::Wall, Tile::Wall].into_iter(),
+            Tile::Box => [Tile::BoxLeft, Self::BoxRight].into_iter(),
+            Tile::Robot => [Tile::Robot, Tile::Empty].into_iter(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '@' => Ok(Self::Robot),
+            _ => bail!("`{value}` is not a warehouse tile"),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::Robot => '@',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Robot {
+    pos: Vector,
+    movement: VecDeque<Dir>,
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    grid: Grid<Tile>,
+    robot: Robot,
+}
+
+impl Warehouse {
+    fn new_from_map(grid: Grid<Tile>, pos: Vector, movement: VecDeque<Dir>) -> Self {
+        Self {
+            grid,
+            robot: Robot { pos, movement },
+        }
+    }
+
+    /// The warehouse from part 2, where everything but the robot is twice as wide.
+    pub fn widen(&self) -> Result<Self> {
+        let rows = self
+            .grid
+            .rows()
+            .map(|row| row.iter().copied().flat_map(Tile::double).collect())
+            .collect();
+
+        Ok(Self {
+            grid: Grid::from_rows(rows)?,
+            robot: Robot {
+                pos: Vector(self.robot.pos.0, self.robot.pos.1 * 2),
+                movement: self.robot.movement.clone(),
+            },
+        })
+    }
+
+    /// Runs every remaining move, recording the warehouse before the first and after each one.
+    pub fn record_moves(&self, recorder: &mut Recorder) {
+        let mut warehouse = self.clone();
+        recorder.record(|| warehouse.render());
+        while !warehouse.robot.movement.is_empty() {
+            warehouse.move_robot();
+            recorder.record(|| warehouse.render());
+        }
+    }
+
+    pub fn render(&self) -> Frame {
+        let mut frame = Frame::from_grid(&self.grid, |&tile| tile.into());
+        frame
+            .color_char('#', Some(Rgb::GREY), None)
+            .overlay([self.robot.pos], None, Some(Rgb::RED), None);
+        frame
+    }
+
+    fn move_robot(&mut self) {
+        let dir = self.robot.movement.pop_front().unwrap();
+
+        if self.can_move(self.robot.pos, dir) {
+            self.move_tile(self.robot.pos, dir);
+            self.robot.pos += dir.into();
+        }
+    }
+
+    fn move_tile(&mut self, pos: Vector, dir: Dir) {
+        let next_pos = pos + dir.into();
+
+        match self.grid[next_pos] {
+            Tile::Empty => {},
+            Tile::Box => self.move_tile(next_pos, dir),
+            Tile::BoxLeft => {
+                self.move_tile(Vector(next_pos.0, next_pos.1 + 1), dir);
+                self.move_tile(next_pos, dir);
+            },
+            Tile::BoxRight => {
+                self.move_tile(Vector(next_pos.0, next_pos.1 - 1), dir);
+                self.move_tile(next_pos, dir);
+            },
+            Tile::Wall => unreachable!(),
+            Tile::Robot => unreachable!(),
+        }
+
+        // Whatever was in the way has moved on, so the next tile is empty now.
+        self.grid.swap(pos, next_pos);
+    }
+
+    fn can_move(&self, pos: Vector, dir: Dir) -> bool {
+        let next_pos = pos + dir.into();
+
+        match self.grid[next_pos] {
+            Tile::Empty => true,
+            Tile::Wall => false,
+            Tile::Box => self.can_move(next_pos, dir),
+            Tile::BoxLeft => {
+                if dir == Dir::LEFT {
+                    self.can_move(next_pos, dir)
+                } else if dir == Dir::RIGHT {
+                    self.can_move(Vector(next_pos.0, next_pos.1 + 1), dir)
+                } else {
+                    self.can_move(Vector(next_pos.0, next_pos.1 + 1), dir) &&
+                        self.can_move(next_pos, dir)
+                }
+            },
+            Tile::BoxRight =>{
+                if dir == Dir::RIGHT {
+                    self.can_move(next_pos, dir)
+                } else if dir == Dir::LEFT {
+                    self.can_move(Vector(next_pos.0, next_pos.1 - 1), dir)
+                } else {
+                    self.can_move(Vector(next_pos.0, next_pos.1 - 1), dir) &&
+                        self.can_move(next_pos, dir)
+                }
+            },
+            Tile::Robot => unreachable!(),
+        }
+    }
+
+    fn gps_coordinate(pos: Vector) -> usize {
+        pos.0 as usize * 100 + pos.1 as usize
+    }
+
+    fn sum_gps(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Box || tile == Tile::BoxLeft)
+            .map(|(pos, _)| Self::gps_coordinate(pos))
+            .sum()
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Warehouse> {
+    let input = parse::read_input(reader)?;
+    let [map, movement] = parse::sections_n(&input)?;
+
+    let mut moves = VecDeque::new();
+    for line in movement.lines() {
+        for (i, c) in line.text.char_indices() {
+            let dir = Dir::try_from(c).map_err(|_| line.error(i, c.len_utf8(), format!("expected a move, found `{c}`")))?;
+            moves.push_back(dir);
+        }
+    }
+
+    let grid = map.try_grid(|c| Tile::try_from(c).ok(), "`#`, `.`, `O` or `@`")?;
+    let robot = map.find_one(&grid, &Tile::Robot, "a robot `@`")?;
+    // Moves are only bounds checked by running into walls, so the map has to be walled in.
+    let (bottom, right) = (grid.height() as i32 - 1, grid.width() as i32 - 1);
+    let gap = grid.iter().find(|&(Vector(row, col), &tile)| {
+        (row == 0 || row == bottom || col == 0 || col == right) && tile != Tile::Wall
+    });
+    if let Some((pos, _)) = gap {
+        return Err(map.error_at(pos, "expected the warehouse to be walled in by `#`"));
+    }
+
+    Ok(Warehouse::new_from_map(grid, robot, moves))
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+
+    type Input = Warehouse;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(warehouse: &Self::Input) -> Result<impl Display> {
+        let mut warehouse = warehouse.clone();
+
+        while !warehouse.robot.movement.is_empty() {
+            warehouse.move_robot();
+        }
+        Ok(warehouse.sum_gps())
+    }
+
+    fn part2(warehouse: &Self::Input) -> Result<impl Display> {
+        let mut warehouse = warehouse.widen()?;
+
+        while !warehouse.robot.movement.is_empty() {
+            warehouse.move_robot();
+        }
+        Ok(warehouse.sum_gps())
+    }
+}
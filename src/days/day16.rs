@@ -0,0 +1,124 @@
+use anyhow::*;
+use crate::grid::{Dir, Grid, Vector};
+use crate::render::{Frame, Rgb};
+use crate::search::{self, SearchResult};
+use itertools::Itertools;
+use std::io::BufRead;
+use core::fmt::Display;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day16;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Tile {
+    Start,
+    End,
+    Wall,
+    Empty,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Empty),
+            _ => bail!("`{value}` is not a maze tile"),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Start => 'S',
+            Tile::End => 'E',
+            Tile::Wall => '#',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+/// Where the reindeer is and which way it's facing.
+type Reindeer = (Vector, Dir);
+
+pub struct Maze {
+    grid: Grid<Tile>,
+    start: Vector,
+}
+
+impl Maze {
+    fn new_from_map(map: &str) -> Result<Self> {
+        let [section] = parse::sections_n(map)?;
+        let grid = section.try_grid(|c| Tile::try_from(c).ok(), "`#`, `.`, `S` or `E`")?;
+        let start = section.find_one(&grid, &Tile::Start, "a start `S`")?;
+        section.find_one(&grid, &Tile::End, "an end `E`")?;
+
+        Ok(Maze { grid, start })
+    }
+
+    fn shortest_paths(&self) -> SearchResult<Reindeer> {
+        search::dijkstra(
+            [(self.start, Dir::RIGHT)],
+            |&reindeer| self.get_neighbors(reindeer),
+            |&(pos, _)| self.grid[pos] == Tile::End,
+        )
+    }
+
+    /// Moves from `reindeer`: a step forwards, or a turn either way followed by a step.
+    fn get_neighbors(&self, (pos, dir): Reindeer) -> Vec<(Reindeer, usize)> {
+        [(dir, 1), (dir.turn_left(), 1001), (dir.turn_right(), 1001)]
+            .into_iter()
+            .filter(|&(dir, _)| self.can_move(pos, dir))
+            .map(|(dir, cost)| ((pos + dir.into(), dir), cost))
+            .collect()
+    }
+
+    /// The maze with every tile on a best path marked `O`.
+    pub fn render_best_paths(&self) -> Frame {
+        let paths = self.shortest_paths();
+        let mut frame = Frame::from_grid(&self.grid, |&tile| tile.into());
+        frame
+            .color_char('#', Some(Rgb::GREY), None)
+            .overlay(paths.nodes_on_shortest_paths(paths.goals()).into_iter().map(|(pos, _)| pos), Some('O'), Some(Rgb::GREEN), None);
+        frame
+    }
+
+    fn can_move(&self, pos: Vector, dir: Dir) -> bool {
+        self.grid.get(pos + dir.into()).is_some_and(|&tile| tile != Tile::Wall)
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn parse_input<R: BufRead>(reader: R) -> Result<Maze> {
+    Maze::new_from_map(&parse::read_input(reader)?)
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = Maze;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader)
+    }
+
+    fn part1(maze: &Self::Input) -> Result<impl Display> {
+        maze.shortest_paths().goal_distance().context("No path to the end tile")
+    }
+
+    fn part2(maze: &Self::Input) -> Result<impl Display> {
+        let paths = maze.shortest_paths();
+        Ok(paths.nodes_on_shortest_paths(paths.goals()).into_iter().map(|(pos, _)| pos).unique().count())
+    }
+}
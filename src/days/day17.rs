@@ -0,0 +1,220 @@
+use anyhow::*;
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::parse;
+use crate::Solution;
+
+pub struct Day17;
+
+enum OperandType {
+    Literal,
+    Combo,
+}
+
+#[derive(Clone, Copy)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl From<Opcode> for String {
+    fn from(value: Opcode) -> Self {
+        match value {
+            Opcode::Adv => "ADV".to_string(),
+            Opcode::Bxl => "BXL".to_string(),
+            Opcode::Bst => "BST".to_string(),
+            Opcode::Jnz => "JNZ".to_string(),
+            Opcode::Bxc => "BXC".to_string(),
+            Opcode::Out => "OUT".to_string(),
+            Opcode::Bdv => "BDV".to_string(),
+            Opcode::Cdv => "CDV".to_string(),
+        }
+    }
+}
+
+impl TryFrom<usize> for Opcode {
+    type Error = anyhow::Error;
+
+    fn try_from(value: usize) -> Result<Self> {
+        Ok(match value {
+            0 => Self::Adv,
+            1 => Self::Bxl,
+            2 => Self::Bst,
+            3 => Self::Jnz,
+            4 => Self::Bxc,
+            5 => Self::Out,
+            6 => Self::Bdv,
+            7 => Self::Cdv,
+            _ => bail!("{value} isn't an opcode"),
+        })
+    }
+}
+
+impl From<Opcode> for OperandType {
+    fn from(value: Opcode) -> Self {
+        match value {
+            Opcode::Adv => Self::Combo,
+            Opcode::Bxl => Self::Literal,
+            Opcode::Bst => Self::Combo,
+            Opcode::Jnz => Self::Literal,
+            Opcode::Bxc => Self::Literal,
+            Opcode::Out => Self::Combo,
+            Opcode::Bdv => Self::Combo,
+            Opcode::Cdv => Self::Combo,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    a: usize,
+    b: usize,
+    c: usize,
+    pc: usize,
+    halted: bool,
+    program: Vec<usize>,
+    output: Vec<usize>,
+}
+
+impl Computer {
+    fn new_from_input<R: BufRead>(reader: R) -> Result<Self> {
+        let input = parse::read_input(reader)?;
+        let [registers, program] = parse::sections_n(&input)?;
+        let register = |name| -> Result<usize> {
+            let line = registers.find(name)?;
+            let [value] = line.ints_n()?;
+            Ok(value)
+        };
+
+        let (a, b, c) = (register("Register A")?, register("Register B")?, register("Register C")?);
+        let line = program.find("Program")?;
+        let (_, values) = line.key_value()?;
+        let values: Vec<&str> = values.split(',').map(str::trim).collect();
+        let program = values
+            .iter()
+            .map(|value| match line.parse_part(value)? {
+                n @ 0..=7 => Ok(n),
+                _ => Err(line.error(line.offset_of(value), value.len(), "expected a 3-bit number, 0 to 7")),
+            })
+            .collect::<Result<Vec<usize>>>()?;
+        for (i, pair) in program.chunks_exact(2).enumerate() {
+            let combo = matches!(OperandType::from(Opcode::try_from(pair[0])?), OperandType::Combo);
+            if combo && pair[1] == 7 {
+                let value = values[2 * i + 1];
+                return Err(line.error(line.offset_of(value), value.len(), "combo operand 7 is reserved"));
+            }
+        }
+
+        Ok(Self {
+            a,
+            b,
+            c,
+            pc: 0,
+            halted: false,
+            program,
+            output: Vec::new(),
+        })
+    }
+
+    fn step(&mut self) -> Result<()> {
+        if let (Some(&opcode), Some(&operand)) = (self.program.get(self.pc), self.program.get(self.pc + 1)) {
+            let opcode = Opcode::try_from(opcode)?;
+            let operand = match OperandType::from(opcode) {
+                OperandType::Literal => operand,
+                OperandType::Combo => {
+                    match operand {
+                        0 => 0,
+                        1 => 1,
+                        2 => 2,
+                        3 => 3,
+                        4 => self.a,
+                        5 => self.b,
+                        6 => self.c,
+                        operand => bail!("combo operand {operand} is reserved"),
+                    }
+                },
+            };
+
+            self.run_opcode(opcode, operand);
+        } else {
+            self.halted = true;
+        }
+
+        Ok(())
+    }
+
+    fn run_opcode(&mut self, opcode: Opcode, operand: usize) {
+        match opcode {
+            Opcode::Adv => self.a /= 2usize.pow(operand as u32),
+            Opcode::Bxl => self.b ^= operand,
+            Opcode::Bst => self.b = operand % 8,
+            Opcode::Jnz => {
+                if self.a != 0 {
+                    self.pc = operand;
+                    return;
+                }
+            },
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => self.output.push(operand % 8),
+            Opcode::Bdv => self.b = self.a / 2usize.pow(operand as u32),
+            Opcode::Cdv => self.c = self.a / 2usize.pow(operand as u32),
+        }
+
+        self.pc += 2;
+    }
+
+    fn find_quine(&self) -> usize {
+        let mut quines = FxHashSet::default();
+        quines.insert(0);
+        for num in self.program.iter().rev() {
+            let mut new_quines = FxHashSet::default();
+            for curr in quines {
+                for i in 0..8 {
+                    let new = (curr << 3) + i;
+                    if Self::get_out(new) == *num {
+                        new_quines.insert(new);
+                    }
+                }
+            }
+            quines = new_quines;
+        }
+
+        *quines.iter().min().unwrap()
+    }
+
+    fn get_out(a: usize) -> usize {
+        let partial = (a % 8) ^ 1;
+        ((partial ^ (a >> partial)) ^ 5) % 8
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    type Input = Computer;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Computer::new_from_input(reader)
+    }
+
+    fn part1(computer: &Self::Input) -> Result<impl Display> {
+        let mut computer = computer.clone();
+        while !computer.halted {
+            computer.step()?;
+        }
+        Ok(computer.output.iter().join(","))
+    }
+
+    fn part2(computer: &Self::Input) -> Result<impl Display> {
+        Ok(computer.find_quine())
+    }
+}
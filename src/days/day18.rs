@@ -0,0 +1,109 @@
+use anyhow::*;
+use crate::grid::{Grid, Topology, Vector};
+use crate::search;
+use std::io::BufRead;
+use std::fmt::Display;
+use crate::parse;
+use crate::{Params, Solution};
+
+pub struct Day18;
+
+const SIZE: usize = 71;
+const BYTES: usize = 1024;
+
+/// The bytes in the order they fall, and how big the memory space they fall into is.
+#[derive(Debug)]
+pub struct FallingBytes {
+    bytes: Vec<Vector>,
+    size: usize,
+    /// How many bytes have fallen by the time part 1 looks for a path.
+    fallen: usize,
+}
+
+#[derive(Debug)]
+struct Memory {
+    corrupted: Grid<bool>,
+}
+
+impl Memory {
+    fn new(bytes: &[Vector], size: usize) -> Self {
+        let mut corrupted = Grid::new(size, size, false);
+        for &byte in bytes {
+            corrupted[byte] = true;
+        }
+
+        Self { corrupted }
+    }
+
+    fn bfs(&self) -> Option<usize> {
+        let exit = Vector(self.corrupted.height() as i32 - 1, self.corrupted.width() as i32 - 1);
+        let result = search::bfs(
+            [Vector(0, 0)],
+            |&pos| self.corrupted.neighbors4(pos).filter(|&next| !self.corrupted[next]).collect::<Vec<_>>(),
+            |&pos| pos == exit,
+        );
+
+        result.goal_distance()
+    }
+}
+
+/// Finds how many bytes can fall before the exit is cut off.
+fn binary_search(bytes: &[Vector], size: usize) -> usize {
+    let mut left = 0;
+    let mut right = bytes.len();
+
+    while right - left > 1 {
+        let mid = left + (right - left) / 2;
+        if Memory::new(&bytes[..mid], size).bfs().is_some() {
+            left = mid;
+        } else {
+            right = mid;
+        }
+    }
+
+    left
+}
+
+fn parse_input<R: BufRead>(reader: R, params: &Params) -> Result<FallingBytes> {
+    let size = params.get_or("size", SIZE);
+    ensure!(size > 0, "The memory space needs to be at least 1x1");
+
+    let bytes = parse::lines(&parse::read_input(reader)?)
+        .map(|line| {
+            let [x, y]: [i32; 2] = line.ints_n()?;
+            let range = 0..size as i32;
+            if !range.contains(&x) || !range.contains(&y) {
+                return Err(line.error(0, line.text.len(), format!("byte {x},{y} is outside the {size}x{size} memory space")));
+            }
+            Ok(Vector(y, x))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(FallingBytes { bytes, size, fallen: params.get_or("bytes", BYTES) })
+}
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "RAM Run";
+
+    type Input = FallingBytes;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        parse_input(reader, &Params::default())
+    }
+
+    fn parse_with<R: BufRead>(reader: R, params: &Params) -> Result<Self::Input> {
+        parse_input(reader, params)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        let fallen = &input.bytes[..input.fallen.min(input.bytes.len())];
+        Memory::new(fallen, input.size).bfs().context("No path to the exit")
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        ensure!(Memory::new(&input.bytes, input.size).bfs().is_none(), "No byte cuts off the exit");
+        let byte = input.bytes[binary_search(&input.bytes, input.size)];
+        Ok(format!("{},{}", byte.1, byte.0))
+    }
+}
@@ -1,8 +1,41 @@
-use std::ops::{Add, AddAssign};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
+use anyhow::{bail, Result};
+use rustc_hash::FxHashMap;
+
+/// A position or offset on a grid, as `(row, col)` with rows growing downwards.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vector(pub i32, pub i32);
 
+impl Vector {
+    /// Number of orthogonal steps between two positions.
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+
+    /// Number of steps between two positions when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Self) -> i32 {
+        (self.0 - other.0).abs().max((self.1 - other.1).abs())
+    }
+
+    /// Wraps both components into `0..bounds`, as on a grid whose edges join up.
+    pub fn rem_euclid(self, bounds: Self) -> Self {
+        Self(self.0.rem_euclid(bounds.0), self.1.rem_euclid(bounds.1))
+    }
+
+    /// Rotates 90 degrees clockwise as seen on screen, e.g. up becomes right.
+    pub fn rotate_right(self) -> Self {
+        Self(self.1, -self.0)
+    }
+
+    /// Rotates 90 degrees anticlockwise as seen on screen, e.g. up becomes left.
+    pub fn rotate_left(self) -> Self {
+        Self(-self.1, self.0)
+    }
+}
+
 impl Add for Vector {
     type Output = Self;
 
@@ -17,6 +50,60 @@ impl AddAssign for Vector {
     }
 }
 
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl MulAssign<i32> for Vector {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl From<(usize, usize)> for Vector {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self(row as i32, col as i32)
+    }
+}
+
+/// Converts back to `(row, col)` indices, failing for negative components.
+impl TryFrom<Vector> for (usize, usize) {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vector) -> Result<Self> {
+        match (usize::try_from(value.0), usize::try_from(value.1)) {
+            (Ok(row), Ok(col)) => Ok((row, col)),
+            _ => bail!("{value:?} has a negative component"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Dir {
     UP,
@@ -25,10 +112,39 @@ pub enum Dir {
     RIGHT,
 }
 
-impl Dir{
+impl Dir {
     pub fn iter() -> impl Iterator<Item = Self> {
         [Self::UP, Self::LEFT, Self::DOWN, Self::RIGHT].into_iter()
     }
+
+    /// The direction after a 90 degree clockwise turn.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir::UP => Dir::RIGHT,
+            Dir::RIGHT => Dir::DOWN,
+            Dir::DOWN => Dir::LEFT,
+            Dir::LEFT => Dir::UP,
+        }
+    }
+
+    /// The direction after a 90 degree anticlockwise turn.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir::UP => Dir::LEFT,
+            Dir::LEFT => Dir::DOWN,
+            Dir::DOWN => Dir::RIGHT,
+            Dir::RIGHT => Dir::UP,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Dir::UP => Dir::DOWN,
+            Dir::DOWN => Dir::UP,
+            Dir::LEFT => Dir::RIGHT,
+            Dir::RIGHT => Dir::LEFT,
+        }
+    }
 }
 
 impl From<Dir> for Vector {
@@ -42,32 +158,574 @@ impl From<Dir> for Vector {
     }
 }
 
-impl From<Vector> for Dir {
-    fn from(value: Vector) -> Self {
+/// Converts a unit step back to its direction, failing for anything else.
+impl TryFrom<Vector> for Dir {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Vector) -> Result<Self> {
         match value {
-            Vector(-1, 0) => Dir::UP,
-            Vector(1, 0) => Dir::DOWN,
-            Vector(0, -1) => Dir::LEFT,
-            Vector(0, 1) => Dir::RIGHT,
-            _ => unimplemented!(),
+            Vector(-1, 0) => Ok(Dir::UP),
+            Vector(1, 0) => Ok(Dir::DOWN),
+            Vector(0, -1) => Ok(Dir::LEFT),
+            Vector(0, 1) => Ok(Dir::RIGHT),
+            _ => bail!("{value:?} is not a unit step in one direction"),
         }
     }
 }
 
-impl From<char> for Dir {
-    fn from(value: char) -> Self {
+/// Parses the arrows puzzles use for directions: `^`, `v`, `<` and `>`.
+impl TryFrom<char> for Dir {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
         match value {
-            '>' => Dir::RIGHT,
-            '^' => Dir::UP,
-            '<' => Dir::LEFT,
-            'v' => Dir::DOWN,
-            _ => unreachable!()
+            '>' => Ok(Dir::RIGHT),
+            '^' => Ok(Dir::UP),
+            '<' => Ok(Dir::LEFT),
+            'v' => Ok(Dir::DOWN),
+            _ => bail!("`{value}` is not a direction"),
         }
     }
 }
 
-pub trait Grid {
-    fn new_from_map(map: &str) -> Self;
-    fn can_move(&self, pos: Vector, dir: Dir) -> bool;
-    fn is_in_map(&self, vec: Vector) -> bool;
+/// One of the eight directions to a neighbouring cell, including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 8] = [Self::N, Self::NE, Self::E, Self::SE, Self::S, Self::SW, Self::W, Self::NW];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        matches!(self, Self::NE | Self::SE | Self::SW | Self::NW)
+    }
+
+    /// The direction after a 45 degree clockwise turn.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after a 45 degree anticlockwise turn.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Compass> for Vector {
+    fn from(value: Compass) -> Self {
+        match value {
+            Compass::N => Vector(-1, 0),
+            Compass::NE => Vector(-1, 1),
+            Compass::E => Vector(0, 1),
+            Compass::SE => Vector(1, 1),
+            Compass::S => Vector(1, 0),
+            Compass::SW => Vector(1, -1),
+            Compass::W => Vector(0, -1),
+            Compass::NW => Vector(-1, -1),
+        }
+    }
+}
+
+impl From<Dir> for Compass {
+    fn from(value: Dir) -> Self {
+        match value {
+            Dir::UP => Compass::N,
+            Dir::RIGHT => Compass::E,
+            Dir::DOWN => Compass::S,
+            Dir::LEFT => Compass::W,
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row in one buffer, addressed by `Vector(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            bail!("Row {row} has {} cells, expected {width}", rows[row].len());
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Vector) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Vector) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Vector) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Swaps two cells, panicking if either is outside the grid.
+    pub fn swap(&mut self, a: Vector, b: Vector) {
+        let (a, b) = (self.expect_index(a), self.expect_index(b));
+        self.cells.swap(a, b);
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero size, which an empty grid would otherwise ask for.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = if col < self.width { &self.cells[col..] } else { &[] };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vector> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Vector::from((index / width, index % width)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Vector>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    /// Positions of every cell equal to `value`, row by row.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Vector> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    /// Builds a grid of the same size by applying `f` to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, pos: Vector) -> Option<usize> {
+        let (row, col) = <(usize, usize)>::try_from(pos).ok()?;
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+
+    fn expect_index(&self, pos: Vector) -> usize {
+        self.index_of(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> Index<Vector> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector) -> &Self::Output {
+        &self.cells[self.expect_index(pos)]
+    }
+}
+
+impl<T> IndexMut<Vector> for Grid<T> {
+    fn index_mut(&mut self, pos: Vector) -> &mut Self::Output {
+        let index = self.expect_index(pos);
+        &mut self.cells[index]
+    }
+}
+
+/// Parses one cell per character, one row per line.
+impl<T: From<char>> FromStr for Grid<T> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rows: Vec<Vec<T>> = s.lines().map(|line| line.chars().map(T::from).collect()).collect();
+        if rows.is_empty() {
+            bail!("Grid is empty");
+        }
+
+        Self::from_rows(rows)
+    }
+}
+
+/// Prints one character per cell, one row per line.
+impl<T: Copy> Display for Grid<T>
+where
+    char: From<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().map(|&cell| char::from(cell)).collect::<String>())?;
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// How positions connect to each other: which ones exist and where stepping off an edge leads.
+///
+/// Search code written against this trait works the same on a bounded [`Grid`], a wrapping [`Torus`]
+/// or an unbounded [`SparseGrid`].
+pub trait Topology {
+    /// Maps `pos` onto the topology, or `None` if it falls off the edge.
+    fn normalize(&self, pos: Vector) -> Option<Vector>;
+
+    /// The orthogonal neighbours of `pos` that exist.
+    fn neighbors4(&self, pos: Vector) -> impl Iterator<Item = Vector> + '_ {
+        Dir::iter().filter_map(move |dir| self.normalize(pos + dir.into()))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that exist.
+    fn neighbors8(&self, pos: Vector) -> impl Iterator<Item = Vector> + '_ {
+        Compass::iter().filter_map(move |dir| self.normalize(pos + dir.into()))
+    }
+}
+
+impl<T> Topology for Grid<T> {
+    fn normalize(&self, pos: Vector) -> Option<Vector> {
+        self.contains(pos).then_some(pos)
+    }
+}
+
+/// A `width` by `height` field whose opposite edges join up, so stepping off one side comes back on the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Torus {
+    width: usize,
+    height: usize,
+}
+
+impl Torus {
+    pub const fn new(width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "A torus needs at least one cell");
+        Self { width, height }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Wraps any position onto the torus.
+    pub fn wrap(&self, pos: Vector) -> Vector {
+        pos.rem_euclid(Vector(self.height as i32, self.width as i32))
+    }
+}
+
+impl Topology for Torus {
+    fn normalize(&self, pos: Vector) -> Option<Vector> {
+        Some(self.wrap(pos))
+    }
+}
+
+/// An unbounded grid that only stores the cells that have been set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<Vector, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self { cells: FxHashMap::default() }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Vector) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Vector) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Vector) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Sets a cell, returning what was there before.
+    pub fn insert(&mut self, pos: Vector, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Vector) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Every set cell along with its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The smallest and largest corner of the box around every set cell, or `None` if nothing is set.
+    pub fn bounds(&self) -> Option<(Vector, Vector)> {
+        let rows = self.cells.keys().map(|pos| pos.0);
+        let cols = self.cells.keys().map(|pos| pos.1);
+        Some((Vector(rows.clone().min()?, cols.clone().min()?), Vector(rows.max()?, cols.max()?)))
+    }
+}
+
+impl<T> FromIterator<(Vector, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vector, T)>>(iter: I) -> Self {
+        Self { cells: iter.into_iter().collect() }
+    }
+}
+
+/// Every position exists, whether or not a cell has been set there.
+impl<T> Topology for SparseGrid<T> {
+    fn normalize(&self, pos: Vector) -> Option<Vector> {
+        Some(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Vector(2, -3);
+        let b = Vector(-1, 4);
+        assert_eq!(Vector(1, 1), a + b);
+        assert_eq!(Vector(3, -7), a - b);
+        assert_eq!(Vector(-2, 3), -a);
+        assert_eq!(Vector(6, -9), a * 3);
+
+        let mut c = a;
+        c -= b;
+        c *= 2;
+        assert_eq!(Vector(6, -14), c);
+    }
+
+    #[test]
+    fn vector_geometry() {
+        assert_eq!(10, Vector(2, -3).manhattan(Vector(-1, 4)));
+        assert_eq!(7, Vector(2, -3).chebyshev(Vector(-1, 4)));
+        assert_eq!(Vector(1, 98), Vector(-102, -3).rem_euclid(Vector(103, 101)));
+
+        let up = Vector::from(Dir::UP);
+        assert_eq!(Vector::from(Dir::RIGHT), up.rotate_right());
+        assert_eq!(Vector::from(Dir::LEFT), up.rotate_left());
+        assert_eq!(Vector(-3, 2), Vector(2, 3).rotate_left());
+        assert_eq!(Vector(2, 3), Vector(2, 3).rotate_right().rotate_left());
+        assert_eq!(-up, up.rotate_right().rotate_right());
+    }
+
+    #[test]
+    fn vector_indices() {
+        assert_eq!(Vector(3, 7), Vector::from((3_usize, 7_usize)));
+        assert_eq!((3, 7), <(usize, usize)>::try_from(Vector(3, 7)).unwrap());
+        assert!(<(usize, usize)>::try_from(Vector(-1, 7)).is_err());
+    }
+
+    #[test]
+    fn turning() {
+        for dir in Dir::iter() {
+            assert_eq!(dir, dir.turn_right().turn_left());
+            assert_eq!(dir.opposite(), dir.turn_right().turn_right());
+            assert_eq!(Vector::from(dir).rotate_right(), Vector::from(dir.turn_right()));
+            assert_eq!(-Vector::from(dir), Vector::from(dir.opposite()));
+            assert_eq!(dir, Dir::try_from(Vector::from(dir)).unwrap());
+        }
+
+        assert!(Dir::try_from(Vector(1, 1)).is_err());
+        assert_eq!(Dir::LEFT, Dir::try_from('<').unwrap());
+        assert!(Dir::try_from('x').is_err());
+    }
+
+    #[test]
+    fn compass() {
+        assert_eq!(8, Compass::iter().map(Vector::from).collect::<std::collections::HashSet<_>>().len());
+        assert_eq!(4, Compass::iter().filter(|dir| dir.is_diagonal()).count());
+
+        for dir in Compass::iter() {
+            assert_eq!(1, Vector::from(dir).chebyshev(Vector(0, 0)));
+            assert_eq!(-Vector::from(dir), Vector::from(dir.opposite()));
+            assert_eq!(dir, dir.turn_right().turn_left());
+        }
+
+        assert_eq!(Compass::NE, Compass::N.turn_right());
+        assert_eq!(Compass::NW, Compass::N.turn_left());
+        for dir in Dir::iter() {
+            assert_eq!(Vector::from(dir), Vector::from(Compass::from(dir)));
+        }
+    }
+
+    /// Positions reachable from `start` in at most `steps` orthogonal steps, on any topology.
+    fn reachable(topology: &impl Topology, start: Vector, steps: usize) -> std::collections::HashSet<Vector> {
+        let mut seen = std::collections::HashSet::from([start]);
+        let mut frontier = vec![start];
+        for _ in 0..steps {
+            frontier = frontier
+                .into_iter()
+                .flat_map(|pos| topology.neighbors4(pos).collect::<Vec<_>>())
+                .filter(|&pos| seen.insert(pos))
+                .collect();
+        }
+        seen
+    }
+
+    #[test]
+    fn neighbors_on_a_grid() {
+        let grid = Grid::new(3, 2, '.');
+        assert_eq!(vec![Vector(1, 0), Vector(0, 1)], grid.neighbors4(Vector(0, 0)).collect::<Vec<_>>());
+        assert_eq!(3, grid.neighbors8(Vector(0, 0)).count());
+        assert_eq!(5, grid.neighbors8(Vector(1, 1)).count());
+        assert_eq!(6, reachable(&grid, Vector(0, 0), 10).len());
+    }
+
+    #[test]
+    fn neighbors_on_a_torus() {
+        let torus = Torus::new(101, 103);
+        assert_eq!(Vector(102, 100), torus.wrap(Vector(-1, -1)));
+        assert_eq!(Vector(0, 0), torus.wrap(Vector(103, 101)));
+
+        let neighbors: Vec<_> = torus.neighbors4(Vector(0, 0)).collect();
+        assert_eq!(vec![Vector(102, 0), Vector(0, 100), Vector(1, 0), Vector(0, 1)], neighbors);
+        assert_eq!(8, torus.neighbors8(Vector(0, 0)).count());
+        assert_eq!(6, reachable(&Torus::new(3, 2), Vector(0, 0), 10).len());
+    }
+
+    #[test]
+    fn sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Vector(-5, 2), '#'), (Vector(3, -1), '#')].into_iter().collect();
+        assert_eq!(2, grid.len());
+        assert_eq!(Some((Vector(-5, -1), Vector(3, 2))), grid.bounds());
+        assert_eq!(Some('#'), grid.insert(Vector(3, -1), 'O'));
+        assert_eq!(Some(&'O'), grid.get(Vector(3, -1)));
+        assert_eq!(None, grid.get(Vector(0, 0)));
+        assert_eq!(Some('#'), grid.remove(Vector(-5, 2)));
+        assert_eq!(1, grid.iter().count());
+
+        assert_eq!(4, grid.neighbors4(Vector(1_000_000, 0)).count());
+        assert_eq!(25, reachable(&grid, Vector(0, 0), 3).len());
+        assert_eq!(None, SparseGrid::<char>::new().bounds());
+    }
+
+    const MAP: &str = "#.#\n..S\n";
+
+    #[test]
+    fn parse_and_index() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Some(&'S'), grid.get(Vector(1, 2)));
+        assert_eq!('#', grid[Vector(0, 2)]);
+        assert_eq!(None, grid.get(Vector(2, 0)));
+        assert_eq!(None, grid.get(Vector(0, -1)));
+        assert_eq!(None, grid.get(Vector(0, 3)));
+        assert!(grid.contains(Vector(1, 0)));
+        assert!(!grid.contains(Vector(-1, 0)));
+        assert_eq!(MAP, grid.to_string());
+
+        assert!("ab\nc\n".parse::<Grid<char>>().is_err());
+        assert!("".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(vec!["#.#", "..S"], rows);
+        assert_eq!(Some(&['.', '.', 'S'][..]), grid.row(1));
+        assert_eq!(None, grid.row(2));
+
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(vec!["#.", "..", "#S"], columns);
+        assert_eq!(0, grid.column(3).count());
+    }
+
+    #[test]
+    fn search_and_update() {
+        let mut grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(Some(Vector(1, 2)), grid.find(&'S'));
+        assert_eq!(None, grid.find(&'E'));
+        assert_eq!(vec![Vector(0, 0), Vector(0, 2)], grid.positions_of(&'#').collect::<Vec<_>>());
+
+        grid[Vector(1, 0)] = 'E';
+        *grid.get_mut(Vector(0, 1)).unwrap() = '#';
+        assert_eq!(None, grid.get_mut(Vector(5, 5)));
+        grid.swap(Vector(1, 0), Vector(1, 2));
+        assert_eq!("###\nS.E\n", grid.to_string());
+
+        let walls = grid.map(|&cell| cell == '#');
+        assert_eq!(3, walls.iter().filter(|(_, &wall)| wall).count());
+        assert_eq!(Grid::new(3, 2, false), grid.map(|_| false));
+    }
 }
\ No newline at end of file
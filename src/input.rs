@@ -0,0 +1,103 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::cache::InputCache;
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The cached input, `NN.txt`.
+    #[default]
+    Cached,
+    /// A named alternate next to the cached input, `NN.<name>.txt`.
+    Alternate(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// A path given on the command line, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Whether this is the input the answers store was recorded from.
+    pub fn is_cached(&self) -> bool {
+        *self == Self::Cached
+    }
+
+    /// Whether this is one particular input rather than each day's own, so it only makes sense for one day.
+    pub fn is_single_input(&self) -> bool {
+        matches!(self, Self::File(_) | Self::Stdin)
+    }
+
+    pub fn read(&self, cache: &InputCache, day: u32) -> Result<Vec<u8>> {
+        let read_file = |path: PathBuf| fs::read(&path).with_context(|| format!("Failed to read {}", path.display()));
+
+        match self {
+            Self::Cached => read_file(cache.path(day)),
+            Self::Alternate(name) => read_file(cache.alternate_path(day, name)),
+            Self::File(path) => read_file(path.clone()),
+            Self::Stdin => {
+                let mut input = Vec::new();
+                io::stdin().read_to_end(&mut input).context("Failed to read stdin")?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Where the input for `day` comes from, for messages.
+    pub fn describe(&self, cache: &InputCache, day: u32) -> String {
+        match self {
+            Self::Cached => cache.path(day).display().to_string(),
+            Self::Alternate(name) => cache.alternate_path(day, name).display().to_string(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cached => write!(f, "cached input"),
+            Self::Alternate(name) => write!(f, "{name}'s input"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_each_source() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache = InputCache::new(&dir);
+        cache.store(6, "mine\n").unwrap();
+        fs::write(cache.alternate_path(6, "alice"), "alice's\n").unwrap();
+
+        assert_eq!(b"mine\n".to_vec(), InputSource::Cached.read(&cache, 6).unwrap());
+        assert_eq!(b"alice's\n".to_vec(), InputSource::Alternate("alice".into()).read(&cache, 6).unwrap());
+        let file = InputSource::from_arg(cache.alternate_path(6, "alice").to_str().unwrap());
+        assert_eq!(b"alice's\n".to_vec(), file.read(&cache, 7).unwrap());
+
+        let missing = InputSource::Alternate("bob".into());
+        assert!(missing.read(&cache, 6).unwrap_err().to_string().ends_with("06.bob.txt"));
+        assert!(missing.describe(&cache, 6).ends_with("06.bob.txt"));
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert!(file.is_single_input() && !missing.is_single_input());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
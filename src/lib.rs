@@ -1,10 +1,110 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::BufRead;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod days;
 pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub mod puzzle;
+pub mod recorder;
+pub mod regions;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod schedule;
+pub mod search;
 
 pub fn start_day(day: &str) {
     println!("Advent of Code 2024 - Day {:0>2}", day);
 }
 
-// Additional common functions
+/// Named numbers that replace a day's puzzle constants, such as the smaller grid its example uses.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, usize>);
+
+impl Params {
+    pub fn new(params: impl IntoIterator<Item = (&'static str, usize)>) -> Self {
+        Self(params.into_iter().map(|(name, value)| (name.to_string(), value)).collect())
+    }
+
+    /// The value of `name`, or `default` if it isn't set.
+    pub fn get_or(&self, name: &str, default: usize) -> usize {
+        self.0.get(name).copied().unwrap_or(default)
+    }
+}
+
+/// A day's puzzle: how to parse the input and how to solve both parts from the parsed input.
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    /// Parses with some puzzle constants replaced, for days whose examples are smaller than the real puzzle.
+    fn parse_with<R: BufRead>(reader: R, _params: &Params) -> Result<Self::Input> {
+        Self::parse(reader)
+    }
+    fn part1(input: &Self::Input) -> Result<impl Display>;
+    fn part2(input: &Self::Input) -> Result<impl Display>;
+}
+
+/// Object-safe view of a [`Solution`], so days with different input types can live in one registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u32;
+    fn title(&self) -> &'static str;
+    fn parse(&self, input: &[u8]) -> Result<Box<dyn Any>>;
+    fn parse_with(&self, input: &[u8], params: &Params) -> Result<Box<dyn Any>>;
+    fn part1(&self, input: &dyn Any) -> Result<String>;
+    fn part2(&self, input: &dyn Any) -> Result<String>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &[u8]) -> Result<Box<dyn Any>> {
+        let input = S::parse(input).with_context(|| format!("Invalid input for day {}", S::DAY))?;
+        Ok(Box::new(input))
+    }
+
+    fn parse_with(&self, input: &[u8], params: &Params) -> Result<Box<dyn Any>> {
+        let input = S::parse_with(input, params).with_context(|| format!("Invalid input for day {}", S::DAY))?;
+        Ok(Box::new(input))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String> {
+        let input = input.downcast_ref().context("Input was not parsed by this day")?;
+        Ok(S::part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<String> {
+        let input = input.downcast_ref().context("Input was not parsed by this day")?;
+        Ok(S::part2(input)?.to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set to run everything on the calling thread even with the `parallel` feature, for deterministic timings.
+static SINGLE_THREADED: AtomicBool = AtomicBool::new(false);
+
+/// Forces the helpers below onto the calling thread, or lets them use the thread pool again.
+pub fn set_single_threaded(single_threaded: bool) {
+    SINGLE_THREADED.store(single_threaded, Ordering::Relaxed);
+}
+
+/// Whether work is spread over threads: only with the `parallel` feature, and not after [`set_single_threaded`].
+pub fn is_parallel() -> bool {
+    cfg!(feature = "parallel") && !SINGLE_THREADED.load(Ordering::Relaxed)
+}
+
+/// `f` applied to every item, in order.
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    map_with(is_parallel(), items, f)
+}
+
+/// Like [`map`], but each thread gets its own scratch state from `init`, e.g. a copy of a map to modify.
+pub fn map_init<T, S, R>(
+    items: &[T],
+    init: impl Fn() -> S + Sync + Send,
+    f: impl Fn(&mut S, &T) -> R + Sync + Send,
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    map_init_with(is_parallel(), items, init, f)
+}
+
+fn map_with<T, R>(parallel: bool, items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    #[cfg(feature = "parallel")]
+    if parallel {
+        use rayon::prelude::*;
+        return items.par_iter().map(f).collect();
+    }
+
+    let _ = parallel;
+    items.iter().map(f).collect()
+}
+
+fn map_init_with<T, S, R>(
+    parallel: bool,
+    items: &[T],
+    init: impl Fn() -> S + Sync + Send,
+    f: impl Fn(&mut S, &T) -> R + Sync + Send,
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    #[cfg(feature = "parallel")]
+    if parallel {
+        use rayon::prelude::*;
+        return items.par_iter().map_init(init, f).collect();
+    }
+
+    let _ = parallel;
+    let mut state = init();
+    items.iter().map(|item| f(&mut state, item)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order_either_way() {
+        let items: Vec<u64> = (0..1000).collect();
+        let expected: Vec<u64> = items.iter().map(|n| n * n).collect();
+
+        for parallel in [true, false] {
+            assert_eq!(expected, map_with(parallel, &items, |n| n * n));
+            assert_eq!(
+                expected,
+                map_init_with(parallel, &items, Vec::new, |scratch: &mut Vec<u64>, n| {
+                    scratch.push(*n);
+                    n * n
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::grid::{Grid, Vector};
+
+/// Where and why the input couldn't be parsed, keeping the offending line so it can be shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column of the first offending character.
+    pub column: usize,
+    /// Number of offending characters, at least 1.
+    pub width: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// The offending line with the bad part underlined, compiler style.
+    pub fn snippet(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        format!(
+            "{gutter} |\n{number} | {}\n{gutter} | {}{}",
+            self.text,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Reads the whole input, failing on I/O errors instead of stopping early.
+pub fn read_input<R: BufRead>(mut reader: R) -> Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input).context("Failed to read the input")?;
+    Ok(input)
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line { number: i + 1, text })
+}
+
+/// The non-empty runs of lines between blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current: Vec<Line> = Vec::new();
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(Section { lines: std::mem::take(&mut current) });
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        sections.push(Section { lines: current });
+    }
+
+    sections
+}
+
+/// Exactly `N` blank-line separated sections, e.g. a map followed by a list of moves.
+pub fn sections_n<const N: usize>(input: &str) -> Result<[Section<'_>; N]> {
+    let sections = sections(input);
+    let found = sections.len();
+    sections.try_into().map_err(|_| {
+        let end = input.lines().count().max(1);
+        anyhow::Error::new(ParseError {
+            line: end,
+            column: 1,
+            width: 1,
+            text: input.lines().last().unwrap_or_default().to_string(),
+            message: format!("expected {N} sections separated by blank lines, found {found}"),
+        })
+    })
+}
+
+/// The whole input as one rectangular grid block.
+pub fn grid<T: From<char>>(input: &str) -> Result<Grid<T>> {
+    let [section] = sections_n(input)?;
+    section.grid()
+}
+
+/// One line of the input and where it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error about the `width` bytes of this line starting at byte `start`.
+    pub fn error(&self, start: usize, width: usize, message: impl Into<String>) -> anyhow::Error {
+        let start = start.min(self.text.len());
+        let end = (start + width).min(self.text.len());
+        anyhow::Error::new(ParseError {
+            line: self.number,
+            column: self.text[..start].chars().count() + 1,
+            width: self.text[start..end].chars().count().max(1),
+            text: self.text.to_string(),
+            message: message.into(),
+        })
+    }
+
+    /// The whole line, trimmed, as a `T`.
+    pub fn parse<T: FromStr>(&self) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        self.parse_part(self.text.trim())
+    }
+
+    /// Every integer on the line, with an optional leading `-`, skipping whatever is between them.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>>
+    where
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            ints.push(self.parse_part(&self.text[start..i])?);
+        }
+
+        Ok(ints)
+    }
+
+    /// Exactly `N` integers, as [`Line::ints`] finds them.
+    pub fn ints_n<T: FromStr, const N: usize>(&self) -> Result<[T; N]>
+    where
+        T::Err: Display,
+    {
+        let ints = self.ints()?;
+        let found = ints.len();
+        ints.try_into()
+            .map_err(|_| self.error(0, self.text.len(), format!("expected {N} numbers, found {found}")))
+    }
+
+    /// Each piece of the line between `separator`s as a `T`.
+    pub fn split<T: FromStr>(&self, separator: &str) -> Result<Vec<T>>
+    where
+        T::Err: Display,
+    {
+        self.text.split(separator).map(|piece| self.parse_part(piece.trim())).collect()
+    }
+
+    /// Each whitespace separated word of the line as a `T`.
+    pub fn words<T: FromStr>(&self) -> Result<Vec<T>>
+    where
+        T::Err: Display,
+    {
+        self.text.split_whitespace().map(|word| self.parse_part(word)).collect()
+    }
+
+    /// A `key: value` pair, both trimmed.
+    pub fn key_value(&self) -> Result<(&'a str, &'a str)> {
+        let (key, value) = self
+            .text
+            .split_once(':')
+            .ok_or_else(|| self.error(0, self.text.len(), "expected `key: value`"))?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Parses `part`, a slice of this line such as a value from [`Line::key_value`], reporting errors at
+    /// its position in the line.
+    pub fn parse_part<T: FromStr>(&self, part: &str) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        part.parse()
+            .map_err(|err| self.error(self.offset_of(part), part.len(), format!("couldn't parse {part:?}: {err}")))
+    }
+
+    /// Where `part`, a slice of this line, starts in it, for pointing errors at it.
+    pub fn offset_of(&self, part: &str) -> usize {
+        (part.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&start| start <= self.text.len())
+            .unwrap_or(0)
+    }
+}
+
+/// A run of consecutive lines, usually one of the blank-line separated [`sections`] of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// Every line as a `key: value` pair, in order.
+    pub fn key_values(&self) -> Result<Vec<(&'a str, &'a str)>> {
+        self.lines.iter().map(Line::key_value).collect()
+    }
+
+    /// The `key: value` line for `key`, failing at the section's first line if there isn't one.
+    pub fn find(&self, key: &str) -> Result<Line<'a>> {
+        self.lines
+            .iter()
+            .find(|line| line.key_value().is_ok_and(|(k, _)| k == key))
+            .copied()
+            .ok_or_else(|| {
+                let first = self.lines[0];
+                first.error(0, first.text.len(), format!("expected a `{key}:` line in this section"))
+            })
+    }
+
+    /// The section as a rectangular grid, one cell per character.
+    pub fn grid<T: From<char>>(&self) -> Result<Grid<T>> {
+        self.try_grid(|c| Some(T::from(c)), "a cell")
+    }
+
+    /// The section as a rectangular grid, failing at any character `cell` rejects as not being `expected`.
+    pub fn try_grid<T>(&self, mut cell: impl FnMut(char) -> Option<T>, expected: &str) -> Result<Grid<T>> {
+        let width = self.lines[0].text.chars().count();
+        let mut rows = Vec::with_capacity(self.lines.len());
+        for line in &self.lines {
+            let len = line.text.chars().count();
+            if len != width {
+                return Err(line.error(0, line.text.len(), format!("expected a row {width} wide, found {len}")));
+            }
+
+            let row = line
+                .text
+                .char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| line.error(i, c.len_utf8(), format!("expected {expected}, found `{c}`"))))
+                .collect::<Result<_>>()?;
+            rows.push(row);
+        }
+
+        Grid::from_rows(rows)
+    }
+
+    /// An error about the cell at `pos` of the grid parsed from this section.
+    pub fn error_at(&self, pos: Vector, message: impl Into<String>) -> anyhow::Error {
+        let line = self.lines[pos.0 as usize];
+        match line.text.char_indices().nth(pos.1 as usize) {
+            Some((start, c)) => line.error(start, c.len_utf8(), message),
+            None => line.error(line.text.len(), 1, message),
+        }
+    }
+
+    /// Where the only `name` cell of `grid`, parsed from this section, is, failing if there are none or several.
+    pub fn find_one<T: PartialEq>(&self, grid: &Grid<T>, value: &T, name: &str) -> Result<Vector> {
+        let mut found = grid.positions_of(value);
+        let first = found.next().ok_or_else(|| {
+            let first = self.lines[0];
+            first.error(0, first.text.len(), format!("expected {name} in this section, found none"))
+        })?;
+        if let Some(second) = found.next() {
+            return Err(self.error_at(second, format!("expected one {name}, found another here")));
+        }
+
+        Ok(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(err: anyhow::Error) -> ParseError {
+        err.downcast().unwrap()
+    }
+
+    #[test]
+    fn finds_integers() {
+        let line = Line { number: 1, text: "p=0,4 v=3,-3 x-y 12-5" };
+        assert_eq!(vec![0, 4, 3, -3, 12, -5], line.ints::<i32>().unwrap());
+        assert_eq!([3, 4], Line { number: 1, text: "Button A: X+3, Y+4" }.ints_n::<u8, 2>().unwrap());
+
+        let err = parse_error(Line { number: 7, text: "a=1 b=-2" }.ints::<u32>().unwrap_err());
+        assert_eq!((7, 7, 2), (err.line, err.column, err.width));
+        assert_eq!("a=1 b=-2", err.text);
+
+        assert_eq!("  |\n7 | a=1 b=-2\n  |       ^^", err.snippet());
+
+        let err = parse_error(line.ints_n::<i32, 2>().unwrap_err());
+        assert_eq!("line 1, column 1: expected 2 numbers, found 6", err.to_string());
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "47|53\n97|13\n\n\n75,47,61\n75,x\n";
+        let sections = sections(input);
+        assert_eq!(2, sections.len());
+        assert_eq!(5, sections[1].lines()[0].number);
+        assert_eq!(vec![47, 53], sections[0].lines()[0].split::<u32>("|").unwrap());
+        assert_eq!(vec![7, 5], Line { number: 1, text: " 7  5 " }.words::<u8>().unwrap());
+
+        let line = Line { number: 3, text: "83: 17 5x" };
+        let (_, numbers) = line.key_value().unwrap();
+        assert_eq!("17 5x", numbers);
+        let err = parse_error(numbers.split_whitespace().map(|n| line.parse_part::<u32>(n)).collect::<Result<Vec<_>>>().unwrap_err());
+        assert_eq!((8, 2), (err.column, err.width));
+        let err = parse_error(line.words::<u32>().unwrap_err());
+        assert_eq!((1, 3), (err.column, err.width));
+
+        let err = parse_error(sections[1].lines()[1].split::<u32>(",").unwrap_err());
+        assert_eq!((6, 4, 1), (err.line, err.column, err.width));
+
+        assert!(sections_n::<2>(input).is_ok());
+        let err = parse_error(sections_n::<3>(input).unwrap_err());
+        assert_eq!("expected 3 sections separated by blank lines, found 2", err.message);
+    }
+
+    #[test]
+    fn key_values() {
+        let input = "Register A: 729\nRegister B: 0\n\nProgram: 0,1,5,4\n";
+        let [registers, program] = sections_n(input).unwrap();
+        assert_eq!(vec![("Register A", "729"), ("Register B", "0")], registers.key_values().unwrap());
+        assert_eq!(2, registers.find("Register B").unwrap().number);
+        assert_eq!(vec![0, 1, 5, 4], program.find("Program").unwrap().ints::<u8>().unwrap());
+
+        let err = parse_error(registers.find("Register C").unwrap_err());
+        assert_eq!(1, err.line);
+        assert!(Line { number: 1, text: "no colon" }.key_value().is_err());
+        assert_eq!(Line { number: 1, text: " 42 " }.parse::<u8>().unwrap(), 42);
+    }
+
+    #[test]
+    fn grids() {
+        let [grid, moves] = sections_n("#.#\n.@.\n\n<>\n").unwrap();
+        let grid: Grid<char> = grid.grid().unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(1, moves.grid::<char>().unwrap().height());
+
+        let err = parse_error(sections("##\n#\n##\n")[0].grid::<char>().unwrap_err());
+        assert_eq!((2, "expected a row 2 wide, found 1"), (err.line, err.message.as_str()));
+
+        let heights = sections("0123\n45x7\n")[0].try_grid(|c| c.to_digit(10), "a height");
+        let err = parse_error(heights.unwrap_err());
+        assert_eq!((2, 3, "expected a height, found `x`"), (err.line, err.column, err.message.as_str()));
+        assert!(super::grid::<char>("ab\n\ncd\n").is_err());
+    }
+
+    #[test]
+    fn finds_one_cell() {
+        let section = &sections("#.S\n.#.\n")[0];
+        let grid: Grid<char> = section.grid().unwrap();
+        assert_eq!(Vector(0, 2), section.find_one(&grid, &'S', "a start `S`").unwrap());
+
+        let err = parse_error(section.find_one(&grid, &'E', "an end `E`").unwrap_err());
+        assert_eq!((1, "expected an end `E` in this section, found none"), (err.line, err.message.as_str()));
+
+        let err = parse_error(section.find_one(&grid, &'.', "floor").unwrap_err());
+        assert_eq!((2, 1), (err.line, err.column));
+    }
+}
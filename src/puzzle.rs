@@ -0,0 +1,247 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+
+/// Default directory puzzle descriptions are saved in, relative to the repository root.
+pub const PUZZLE_DIR: &str = "puzzles";
+/// Directory example fixtures live in, relative to the repository root.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Saves the page's description as `puzzles/NN.md`, replacing any older copy so part 2 shows up once unlocked.
+pub fn save_description(page: &str, day: u32) -> Result<PathBuf> {
+    if articles(page).is_empty() {
+        bail!("The page for day {day} has no puzzle description, is it unlocked yet?");
+    }
+
+    fs::create_dir_all(PUZZLE_DIR).with_context(|| format!("Failed to create {PUZZLE_DIR}"))?;
+    let path = Path::new(PUZZLE_DIR).join(format!("{day:02}.md"));
+    fs::write(&path, to_markdown(page)).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Saves the first code block as `examples/NN-1.txt`, returning where it went.
+///
+/// Returns `None` if the page has no code block or the fixture already exists, since that may have been edited by hand.
+pub fn save_example(page: &str, day: u32) -> Result<Option<PathBuf>> {
+    let path = Path::new(EXAMPLES_DIR).join(format!("{day:02}-1.txt"));
+    let Some(example) = first_example(page).filter(|_| !path.exists()) else {
+        return Ok(None);
+    };
+
+    fs::write(&path, example).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(Some(path))
+}
+
+/// The `<article>` bodies of a puzzle page, one per unlocked part.
+pub fn articles(page: &str) -> Vec<&str> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    article
+        .captures_iter(page)
+        .map(|cap| cap.get(1).unwrap().as_str())
+        .collect()
+}
+
+/// Reads the puzzle title out of the `--- Day 1: Historian Hysteria ---` heading.
+pub fn title(page: &str) -> Option<String> {
+    let heading = Regex::new(r"<h2[^>]*>--- Day \d+: (.*?) ---</h2>").unwrap();
+    heading.captures(page).map(|cap| decode_entities(cap.get(1).unwrap().as_str()))
+}
+
+/// The first `<pre><code>` block on the page, which is usually the example input.
+pub fn first_example(page: &str) -> Option<String> {
+    let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    block
+        .captures(page)
+        .map(|cap| decode_entities(&tags.replace_all(cap.get(1).unwrap().as_str(), "")))
+}
+
+/// Converts every article on a puzzle page to Markdown.
+pub fn to_markdown(page: &str) -> String {
+    let parts: Vec<String> = articles(page).into_iter().map(article_to_markdown).collect();
+    let mut markdown = parts.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to Markdown.
+fn article_to_markdown(article: &str) -> String {
+    let tag = Regex::new(r#"<(/?)([a-zA-Z0-9]+)((?:[^>"]|"[^"]*")*)>"#).unwrap();
+    let href = Regex::new(r#"href="([^"]*)""#).unwrap();
+
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links = Vec::new();
+    let mut last = 0;
+
+    for cap in tag.captures_iter(article) {
+        let whole = cap.get(0).unwrap();
+        push_text(&mut out, &article[last..whole.start()], in_pre);
+        last = whole.end();
+
+        let closing = !cap[1].is_empty();
+        match (cap[2].to_ascii_lowercase().as_str(), closing) {
+            ("pre", false) => {
+                start_block(&mut out);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+                in_pre = false;
+            }
+            (_, _) if in_pre => {}
+            ("h2", false) => {
+                start_block(&mut out);
+                out.push_str("## ");
+            }
+            ("h2", true) => {
+                let start = out.rfind("## ").map_or(0, |pos| pos + 3);
+                let heading = out[start..].trim_matches(|c| c == '-' || c == ' ').to_string();
+                out.truncate(start);
+                out.push_str(&heading);
+                out.push_str("\n\n");
+            }
+            ("p", false) => start_block(&mut out),
+            ("p", true) | ("ul", true) => {
+                trim_spaces(&mut out);
+                out.push_str("\n\n");
+            }
+            ("li", false) => {
+                trim_spaces(&mut out);
+                if !out.ends_with('\n') && !out.is_empty() {
+                    out.push('\n');
+                }
+                out.push_str("- ");
+            }
+            ("code", _) => {
+                out.push('`');
+                in_code = !closing;
+            }
+            ("em", _) if !in_code => out.push_str("**"),
+            ("a", false) => {
+                out.push('[');
+                links.push(href.captures(&cap[3]).map_or(String::new(), |cap| cap[1].to_string()));
+            }
+            ("a", true) => {
+                let target = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({target})"));
+            }
+            _ => {}
+        }
+    }
+    push_text(&mut out, &article[last..], in_pre);
+
+    let blank_lines = Regex::new(r"\n{3,}").unwrap();
+    blank_lines.replace_all(out.trim(), "\n\n").to_string()
+}
+
+fn start_block(out: &mut String) {
+    trim_spaces(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+    }
+}
+
+fn trim_spaces(out: &mut String) {
+    out.truncate(out.trim_end_matches(' ').len());
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    if in_pre {
+        out.push_str(&decode_entities(text));
+        return;
+    }
+
+    let text = decode_entities(text);
+    let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.is_empty() {
+        if !text.is_empty() && !out.is_empty() && !out.ends_with(char::is_whitespace) {
+            out.push(' ');
+        }
+        return;
+    }
+    if text.starts_with(char::is_whitespace) && !out.is_empty() && !out.ends_with(char::is_whitespace) {
+        out.push(' ');
+    }
+    if text.ends_with(char::is_whitespace) {
+        collapsed.push(' ');
+    }
+    out.push_str(&collapsed);
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present for the big Christmas sleigh launch.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+<em>2</em>   5
+</code></pre>
+<p>Within each pair, <code>a &lt; b</code> holds, see <a href="/2024/about">the about page</a>:</p>
+<ul>
+<li>The first pair is <code><em>3</em></code>.</li>
+<li>The second pair is <span title="really">fine</span>.</li>
+</ul>
+<p>What is the total distance?</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now <em>multiply</em> them.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn metadata() {
+        assert_eq!(2, articles(PAGE).len());
+        assert_eq!(Some("Historian Hysteria".to_string()), title(PAGE));
+        assert_eq!(Some("3   4\n4   3\n2   5\n".to_string()), first_example(PAGE));
+        assert_eq!(None, first_example("<article><p>No examples</p></article>"));
+    }
+
+    #[test]
+    fn markdown() {
+        let expected = "\
+## Day 1: Historian Hysteria
+
+The **Chief Historian** is always present for the big Christmas sleigh launch.
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+Within each pair, `a < b` holds, see [the about page](/2024/about):
+
+- The first pair is `3`.
+- The second pair is fine.
+
+What is the total distance?
+
+## Part Two
+
+Now **multiply** them.
+";
+        assert_eq!(expected, to_markdown(PAGE));
+    }
+}
@@ -0,0 +1,184 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, ensure, Context, Result};
+
+use crate::render::{png, Frame};
+
+/// Clears the terminal once before a replay.
+const CLEAR_SCREEN: &str = "\x1b[2J";
+/// Moves the cursor home so each frame draws over the last one.
+const CURSOR_HOME: &str = "\x1b[H";
+
+/// Snapshots of a simulation, one [`Frame`] per recorded tick.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    every: usize,
+    ticks: usize,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    /// Records every tick.
+    pub fn new() -> Self {
+        Self::every(1)
+    }
+
+    /// Records only every `n`th tick, starting with the first, for simulations too long to watch in full.
+    pub fn every(n: usize) -> Self {
+        Self {
+            frames: Vec::new(),
+            every: n.max(1),
+            ticks: 0,
+        }
+    }
+
+    /// Counts a tick, drawing a frame with `draw` if this tick is recorded.
+    pub fn record(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.ticks.is_multiple_of(self.every) {
+            self.frames.push(draw());
+        }
+        self.ticks += 1;
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Plays the frames back in a terminal at `fps` frames per second, or as fast as possible for 0.
+    pub fn replay(&self, mut out: impl Write, fps: u32) -> Result<()> {
+        let delay = (fps > 0).then(|| Duration::from_secs(1) / fps);
+
+        write!(out, "{CLEAR_SCREEN}")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            writeln!(out, "{CURSOR_HOME}{}Frame {}/{}", frame.to_ansi(), i + 1, self.len())?;
+            out.flush()?;
+            if let Some(delay) = delay {
+                thread::sleep(delay);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// An animated PNG of the frames at `fps` frames per second, looping forever.
+    pub fn to_apng(&self, scale: usize, fps: u16) -> Result<Vec<u8>> {
+        ensure!(fps > 0, "Animations need at least 1 frame per second");
+        let first = self.frames.first().context("Nothing has been recorded")?;
+        let (width, height) = (first.width(), first.height());
+        ensure!(
+            self.frames.iter().all(|frame| (frame.width(), frame.height()) == (width, height)),
+            "Every frame of an animation must be the same size"
+        );
+
+        let scale = scale.max(1);
+        let frames: Vec<_> = self.frames.iter().map(|frame| frame.scanlines(scale)).collect();
+        Ok(png::encode_animation(width * scale, height * scale, &frames, fps))
+    }
+
+    /// Saves the recording as an animated PNG, or as every frame in turn as plain text.
+    pub fn save(&self, path: impl AsRef<Path>, scale: usize, fps: u16) -> Result<()> {
+        let path = path.as_ref();
+        let contents = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png" | "apng") => self.to_apng(scale, fps)?,
+            Some("txt") => self.frames.iter().map(Frame::to_ascii).collect::<Vec<_>>().join("\n").into_bytes(),
+            _ => bail!("Don't know how to save {}, expected a .png, .apng or .txt file", path.display()),
+        };
+
+        fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Vector;
+
+    fn dot(col: i32) -> Frame {
+        Frame::from_points(3, 1, [Vector(0, col)])
+    }
+
+    fn chunks(png: &[u8]) -> Vec<(String, Vec<u8>)> {
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let kind = String::from_utf8(rest[4..8].to_vec()).unwrap();
+            chunks.push((kind, rest[8..8 + len].to_vec()));
+            rest = &rest[12 + len..];
+        }
+        chunks
+    }
+
+    #[test]
+    fn records_every_nth_tick() {
+        let mut recorder = Recorder::every(2);
+        for col in 0..5 {
+            recorder.record(|| dot(col % 3));
+        }
+        assert_eq!(3, recorder.len());
+        assert_eq!(dot(1), recorder.frames()[2]);
+    }
+
+    #[test]
+    fn replays_in_place() {
+        let mut recorder = Recorder::new();
+        recorder.record(|| dot(0));
+        recorder.record(|| dot(2));
+
+        let mut out = Vec::new();
+        recorder.replay(&mut out, 0).unwrap();
+        assert_eq!(
+            "\x1b[2J\x1b[H#..\nFrame 1/2\n\x1b[H..#\nFrame 2/2\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn apng_chunks() {
+        let mut recorder = Recorder::new();
+        for col in 0..3 {
+            recorder.record(|| dot(col));
+        }
+
+        let chunks = chunks(&recorder.to_apng(2, 10).unwrap());
+        let kinds: Vec<_> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+        assert_eq!(vec!["IHDR", "acTL", "fcTL", "IDAT", "fcTL", "fdAT", "fcTL", "fdAT", "IEND"], kinds);
+
+        assert_eq!([0, 0, 0, 3, 0, 0, 0, 0], chunks[1].1[..]);
+        // Sequence numbers run through the frame controls and frame data alike.
+        let sequence = |i: usize| u32::from_be_bytes(chunks[i].1[..4].try_into().unwrap());
+        assert_eq!(vec![0, 1, 2, 3, 4], [2, 4, 5, 6, 7].map(sequence));
+        // A 6x2 frame shown for 1/10 of a second.
+        assert_eq!([0, 0, 0, 6, 0, 0, 0, 2], chunks[4].1[4..12]);
+        assert_eq!([0, 1, 0, 10], chunks[4].1[20..24]);
+    }
+
+    #[test]
+    fn rejects_bad_animations() {
+        assert!(Recorder::new().to_apng(1, 10).is_err());
+
+        let mut recorder = Recorder::new();
+        recorder.record(|| dot(0));
+        assert!(recorder.to_apng(1, 0).is_err());
+        recorder.record(|| Frame::from_points(2, 2, []));
+        assert!(recorder.to_apng(1, 10).is_err());
+    }
+}
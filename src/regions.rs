@@ -0,0 +1,187 @@
+use crate::grid::{Dir, Grid, Topology, Vector};
+
+/// Every cell reachable from `start` by stepping to orthogonal neighbours, in the order they were reached.
+///
+/// `connected(from, to)` decides whether a step between two cells is allowed, so it can be directional.
+pub fn flood_fill<T>(grid: &Grid<T>, start: Vector, mut connected: impl FnMut(&T, &T) -> bool) -> Vec<Vector> {
+    let mut seen = grid.map(|_| false);
+    let mut cells = Vec::new();
+    if !grid.contains(start) {
+        return cells;
+    }
+
+    seen[start] = true;
+    let mut stack = vec![start];
+    while let Some(pos) = stack.pop() {
+        cells.push(pos);
+        for next in grid.neighbors4(pos) {
+            if !seen[next] && connected(&grid[pos], &grid[next]) {
+                seen[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+
+    cells
+}
+
+/// One connected component of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    cells: Vec<Vector>,
+    perimeter: usize,
+    sides: usize,
+    min: Vector,
+    max: Vector,
+}
+
+impl Region {
+    pub fn cells(&self) -> &[Vector] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges on the region's boundary, including around any holes.
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// Number of straight edges on the region's boundary, including around any holes.
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+
+    /// Top left and bottom right cells of the smallest box containing the region.
+    pub fn bounding_box(&self) -> (Vector, Vector) {
+        (self.min, self.max)
+    }
+}
+
+/// A grid split into connected components, with each cell labelled by the index of its region.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Labels the connected components of `grid`, where neighbouring cells belong together if `same` says so.
+    pub fn label<T>(grid: &Grid<T>, mut same: impl FnMut(&T, &T) -> bool) -> Self {
+        const UNLABELLED: usize = usize::MAX;
+
+        let mut labels = grid.map(|_| UNLABELLED);
+        let mut regions = Vec::new();
+        for pos in grid.positions() {
+            if labels[pos] != UNLABELLED {
+                continue;
+            }
+
+            let cells = flood_fill(grid, pos, &mut same);
+            for &cell in &cells {
+                labels[cell] = regions.len();
+            }
+            regions.push(measure(&labels, regions.len(), cells));
+        }
+
+        Self { labels, regions }
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// The region containing `pos`, if it's on the grid.
+    pub fn region_at(&self, pos: Vector) -> Option<&Region> {
+        self.labels.get(pos).map(|&label| &self.regions[label])
+    }
+
+    /// The grid of region indices.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+}
+
+fn measure(labels: &Grid<usize>, label: usize, cells: Vec<Vector>) -> Region {
+    let inside = |pos: Vector| labels.get(pos) == Some(&label);
+
+    let mut perimeter = 0;
+    let mut corners = 0;
+    let (mut min, mut max) = (cells[0], cells[0]);
+    for &cell in &cells {
+        min = Vector(min.0.min(cell.0), min.1.min(cell.1));
+        max = Vector(max.0.max(cell.0), max.1.max(cell.1));
+
+        for dir in Dir::iter() {
+            let ahead = inside(cell + dir.into());
+            if !ahead {
+                perimeter += 1;
+            }
+
+            // A polygon has as many sides as corners, and each corner belongs to exactly one cell.
+            let side = inside(cell + dir.turn_right().into());
+            let diagonal = inside(cell + dir.into() + dir.turn_right().into());
+            if (!ahead && !side) || (ahead && side && !diagonal) {
+                corners += 1;
+            }
+        }
+    }
+
+    Region {
+        cells,
+        perimeter,
+        sides: corners,
+        min,
+        max,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flood_fill_follows_the_predicate() {
+        let grid: Grid<char> = "aab\nabb\nbba\n".parse().unwrap();
+        assert_eq!(3, flood_fill(&grid, Vector(0, 0), |a, b| a == b).len());
+        assert_eq!(5, flood_fill(&grid, Vector(0, 2), |a, b| a == b).len());
+        assert_eq!(9, flood_fill(&grid, Vector(0, 0), |_, _| true).len());
+        assert!(flood_fill(&grid, Vector(3, 0), |_, _| true).is_empty());
+
+        // Directional steps: only ever uphill.
+        let heights = Grid::from_rows(vec![vec![0, 1, 2], vec![5, 4, 3]]).unwrap();
+        assert_eq!(6, flood_fill(&heights, Vector(0, 0), |a, b| b == &(a + 1)).len());
+        assert_eq!(1, flood_fill(&heights, Vector(1, 0), |a, b| b == &(a + 1)).len());
+    }
+
+    #[test]
+    fn measures_regions() {
+        // The outer O region has four holes, so its sides count the holes' edges too.
+        let grid: Grid<char> = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n".parse().unwrap();
+        let regions = Regions::label(&grid, |a, b| a == b);
+        assert_eq!(5, regions.regions().len());
+
+        let outer = regions.region_at(Vector(0, 0)).unwrap();
+        assert_eq!(21, outer.area());
+        assert_eq!(36, outer.perimeter());
+        assert_eq!(20, outer.sides());
+        assert_eq!((Vector(0, 0), Vector(4, 4)), outer.bounding_box());
+
+        let hole = regions.region_at(Vector(3, 3)).unwrap();
+        assert_eq!((1, 4, 4), (hole.area(), hole.perimeter(), hole.sides()));
+        assert_eq!(regions.labels()[Vector(0, 0)], regions.labels()[Vector(4, 4)]);
+        assert_ne!(regions.labels()[Vector(1, 1)], regions.labels()[Vector(3, 3)]);
+        assert!(regions.region_at(Vector(5, 0)).is_none());
+    }
+
+    #[test]
+    fn counts_sides_of_concave_shapes() {
+        let grid: Grid<char> = "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n".parse().unwrap();
+        let regions = Regions::label(&grid, |a, b| a == b);
+        let e = regions.region_at(Vector(0, 0)).unwrap();
+        assert_eq!((17, 12), (e.area(), e.sides()));
+        assert_eq!((Vector(1, 1), Vector(1, 4)), regions.region_at(Vector(1, 1)).unwrap().bounding_box());
+    }
+}
@@ -0,0 +1,385 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::grid::{Grid, Vector};
+
+/// A 24-bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const GREY: Self = Self(110, 110, 110);
+    pub const RED: Self = Self(220, 50, 47);
+    pub const GREEN: Self = Self(80, 200, 80);
+    pub const BLUE: Self = Self(60, 120, 230);
+    pub const YELLOW: Self = Self(240, 200, 40);
+}
+
+/// One character of a [`Frame`], with optional colours for terminal and image output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+impl Cell {
+    pub fn new(ch: char) -> Self {
+        Self { ch, fg: None, bg: None }
+    }
+
+    /// Whether the cell shows something, as opposed to empty space drawn as `.` or blank.
+    pub fn is_ink(&self) -> bool {
+        !(self.ch == '.' || self.ch.is_whitespace())
+    }
+
+    /// The colour of the cell's pixels in an image: its background, then its foreground, then white ink on black.
+    pub fn color(&self) -> Rgb {
+        self.bg
+            .or(self.fg)
+            .unwrap_or(if self.is_ink() { Rgb::WHITE } else { Rgb::BLACK })
+    }
+}
+
+/// A picture of a grid that can be printed as plain or coloured text, or saved as an image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    /// Draws every cell of `grid` as the character `draw` picks for it.
+    pub fn from_grid<T>(grid: &Grid<T>, mut draw: impl FnMut(&T) -> char) -> Self {
+        Self {
+            cells: grid.map(|cell| Cell::new(draw(cell))),
+        }
+    }
+
+    /// A `width` by `height` frame of `.` with `#` at each of `points`, ignoring points outside it.
+    pub fn from_points(width: usize, height: usize, points: impl IntoIterator<Item = Vector>) -> Self {
+        let mut frame = Self {
+            cells: Grid::new(width, height, Cell::new('.')),
+        };
+        frame.overlay(points, Some('#'), None, None);
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get(&self, pos: Vector) -> Option<&Cell> {
+        self.cells.get(pos)
+    }
+
+    /// Marks `positions`, e.g. a path or the visited cells, replacing the character and colours that are given.
+    pub fn overlay(
+        &mut self,
+        positions: impl IntoIterator<Item = Vector>,
+        ch: Option<char>,
+        fg: Option<Rgb>,
+        bg: Option<Rgb>,
+    ) -> &mut Self {
+        for pos in positions {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.ch = ch.unwrap_or(cell.ch);
+                cell.fg = fg.or(cell.fg);
+                cell.bg = bg.or(cell.bg);
+            }
+        }
+
+        self
+    }
+
+    /// Colours every cell showing `ch`.
+    pub fn color_char(&mut self, ch: char, fg: Option<Rgb>, bg: Option<Rgb>) -> &mut Self {
+        let positions: Vec<_> = self.cells.iter().filter(|(_, cell)| cell.ch == ch).map(|(pos, _)| pos).collect();
+        self.overlay(positions, None, fg, bg)
+    }
+
+    /// Plain text, one line per row.
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::with_capacity((self.width() + 1) * self.height());
+        for row in self.cells.rows() {
+            out.extend(row.iter().map(|cell| cell.ch));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Text with 24-bit ANSI colour escapes for terminals.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.rows() {
+            let mut styled = false;
+            for cell in row {
+                if cell.fg.is_some() || cell.bg.is_some() || styled {
+                    out.push_str("\x1b[0m");
+                    styled = false;
+                }
+                if let Some(Rgb(r, g, b)) = cell.fg {
+                    write!(out, "\x1b[38;2;{r};{g};{b}m").unwrap();
+                    styled = true;
+                }
+                if let Some(Rgb(r, g, b)) = cell.bg {
+                    write!(out, "\x1b[48;2;{r};{g};{b}m").unwrap();
+                    styled = true;
+                }
+                out.push(cell.ch);
+            }
+            if styled {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// A plain PBM bitmap with one pixel per cell, black wherever there is ink.
+    pub fn to_pbm(&self) -> String {
+        let mut out = format!("P1\n{} {}\n", self.width(), self.height());
+        for row in self.cells.rows() {
+            let bits: Vec<&str> = row.iter().map(|cell| if cell.is_ink() { "1" } else { "0" }).collect();
+            out.push_str(&bits.join(" "));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// An RGB PNG with each cell drawn as a `scale` by `scale` square.
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        png::encode(self.width() * scale, self.height() * scale, &self.scanlines(scale))
+    }
+
+    /// The frame's pixels as PNG scanlines, each starting with its filter type.
+    pub(crate) fn scanlines(&self, scale: usize) -> Vec<u8> {
+        let line_len = self.width() * scale * 3 + 1;
+        let mut pixels = Vec::with_capacity(line_len * self.height() * scale);
+        for row in self.cells.rows() {
+            let mut line = Vec::with_capacity(line_len);
+            // These are left unfiltered.
+            line.push(0);
+            for cell in row {
+                let Rgb(r, g, b) = cell.color();
+                for _ in 0..scale {
+                    line.extend([r, g, b]);
+                }
+            }
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        pixels
+    }
+
+    /// Saves the frame as a PNG, a PBM or plain text depending on the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>, scale: usize) -> Result<()> {
+        let path = path.as_ref();
+        let contents = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.to_png(scale),
+            Some("pbm") => self.to_pbm().into_bytes(),
+            Some("txt") => self.to_ascii().into_bytes(),
+            _ => bail!("Don't know how to save {}, expected a .png, .pbm or .txt file", path.display()),
+        };
+
+        fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Just enough of PNG and APNG to write truecolour images: uncompressed deflate blocks inside a zlib stream.
+pub(crate) mod png {
+    const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    const MAX_STORED_BLOCK: usize = 65535;
+
+    /// Encodes already filtered scanlines of 8-bit RGB pixels.
+    pub fn encode(width: usize, height: usize, scanlines: &[u8]) -> Vec<u8> {
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header(width, height));
+        write_chunk(&mut png, b"IDAT", &zlib_stored(scanlines));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Encodes an endlessly looping APNG, showing each frame of scanlines for `1 / fps` seconds.
+    pub fn encode_animation(width: usize, height: usize, frames: &[Vec<u8>], fps: u16) -> Vec<u8> {
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header(width, height));
+
+        let mut animation = Vec::with_capacity(8);
+        animation.extend((frames.len() as u32).to_be_bytes());
+        animation.extend(0u32.to_be_bytes());
+        write_chunk(&mut png, b"acTL", &animation);
+
+        // Frame controls and frame data share one sequence, so viewers can spot chunks out of order.
+        let mut sequence = 0u32;
+        for (i, scanlines) in frames.iter().enumerate() {
+            let mut control = Vec::with_capacity(26);
+            control.extend(sequence.to_be_bytes());
+            control.extend((width as u32).to_be_bytes());
+            control.extend((height as u32).to_be_bytes());
+            control.extend([0; 8]);
+            control.extend(1u16.to_be_bytes());
+            control.extend(fps.to_be_bytes());
+            // Leave the canvas alone after the frame and draw over it without blending.
+            control.extend([0, 0]);
+            write_chunk(&mut png, b"fcTL", &control);
+            sequence += 1;
+
+            if i == 0 {
+                // The first frame doubles as the still image for viewers without APNG support.
+                write_chunk(&mut png, b"IDAT", &zlib_stored(scanlines));
+            } else {
+                let mut data = sequence.to_be_bytes().to_vec();
+                data.extend(zlib_stored(scanlines));
+                write_chunk(&mut png, b"fdAT", &data);
+                sequence += 1;
+            }
+        }
+
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    fn header(width: usize, height: usize) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), default compression, filtering and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        header
+    }
+
+    fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        png.extend((data.len() as u32).to_be_bytes());
+        let start = png.len();
+        png.extend(kind);
+        png.extend(data);
+        let crc = crc32(&png[start..]);
+        png.extend(crc.to_be_bytes());
+    }
+
+    fn zlib_stored(data: &[u8]) -> Vec<u8> {
+        // Deflate with a 32K window and no preset dictionary, with check bits making the header a multiple of 31.
+        let mut out = vec![0x78, 0x01];
+        let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+        if blocks.peek().is_none() {
+            out.extend([1, 0, 0, 0xff, 0xff]);
+        }
+        while let Some(block) = blocks.next() {
+            out.push(u8::from(blocks.peek().is_none()));
+            let len = block.len() as u16;
+            out.extend(len.to_le_bytes());
+            out.extend((!len).to_le_bytes());
+            out.extend(block);
+        }
+        out.extend(adler32(data).to_be_bytes());
+        out
+    }
+
+    pub(super) fn crc32(data: &[u8]) -> u32 {
+        let mut crc = !0u32;
+        for &byte in data {
+            crc ^= u32::from(byte);
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+            }
+        }
+        !crc
+    }
+
+    pub(super) fn adler32(data: &[u8]) -> u32 {
+        let (mut a, mut b) = (1u32, 0u32);
+        for &byte in data {
+            a = (a + u32::from(byte)) % 65521;
+            b = (b + a) % 65521;
+        }
+        (b << 16) | a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> Frame {
+        let grid: Grid<char> = "#.#\n.S.\n".parse().unwrap();
+        Frame::from_grid(&grid, |&c| c)
+    }
+
+    #[test]
+    fn ascii_and_overlays() {
+        let mut frame = maze();
+        assert_eq!("#.#\n.S.\n", frame.to_ascii());
+
+        frame.overlay([Vector(1, 0), Vector(1, 2), Vector(5, 5)], Some('O'), Some(Rgb::RED), None);
+        assert_eq!("#.#\nOSO\n", frame.to_ascii());
+        assert_eq!(Some(Rgb::RED), frame.get(Vector(1, 2)).unwrap().fg);
+
+        let points = Frame::from_points(3, 2, [Vector(0, 1), Vector(1, 2), Vector(2, 0)]);
+        assert_eq!(".#.\n..#\n", points.to_ascii());
+    }
+
+    #[test]
+    fn ansi() {
+        let mut frame = maze();
+        assert_eq!(frame.to_ascii(), frame.to_ansi());
+
+        frame.color_char('S', Some(Rgb::GREEN), Some(Rgb::BLACK));
+        assert_eq!("#.#\n.\x1b[0m\x1b[38;2;80;200;80m\x1b[48;2;0;0;0mS\x1b[0m.\n", frame.to_ansi());
+    }
+
+    #[test]
+    fn pbm() {
+        assert_eq!("P1\n3 2\n1 0 1\n0 1 0\n", maze().to_pbm());
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xcbf4_3926, png::crc32(b"123456789"));
+        assert_eq!(0x091e_01de, png::adler32(b"123456789"));
+        assert_eq!(0xae42_6082, png::crc32(b"IEND"));
+    }
+
+    #[test]
+    fn png_structure() {
+        let png = maze().to_png(2);
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(b"IHDR", &png[12..16]);
+        assert_eq!(6, u32::from_be_bytes(png[16..20].try_into().unwrap()));
+        assert_eq!(4, u32::from_be_bytes(png[20..24].try_into().unwrap()));
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+
+        // 4 scanlines of a filter byte plus 6 RGB pixels, stored in a single deflate block.
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(b"IDAT", &png[37..41]);
+        let zlib = &png[41..41 + idat_len];
+        assert_eq!([0x78, 0x01, 1], zlib[..3]);
+        assert_eq!(4 * 19, u16::from_le_bytes([zlib[3], zlib[4]]) as usize);
+        // The top left pixel is a wall, drawn white.
+        assert_eq!([0, 255, 255, 255], zlib[7..11]);
+    }
+
+    #[test]
+    fn large_png_splits_blocks() {
+        let frame = Frame::from_points(200, 200, []);
+        let png = frame.to_png(1);
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        let raw: usize = 200 * (200 * 3 + 1);
+        // zlib header, one 5 byte header per stored block, the data and the checksum.
+        assert_eq!(2 + raw.div_ceil(65535) * 5 + raw + 4, idat_len);
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use crate::bench::DayTimings;
+
+/// How many of the slowest days a report points out.
+const SLOWEST: usize = 3;
+
+/// Timings of every day of the season, measured against a time budget for running them all.
+#[derive(Debug, Clone)]
+pub struct Report {
+    days: Vec<(&'static str, DayTimings)>,
+    /// Days that couldn't be timed, with why.
+    failures: Vec<(u32, String)>,
+    budget: Duration,
+}
+
+impl Report {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            days: Vec::new(),
+            failures: Vec::new(),
+            budget,
+        }
+    }
+
+    pub fn add(&mut self, title: &'static str, timings: DayTimings) {
+        self.days.push((title, timings));
+    }
+
+    /// Records that `day` couldn't be timed, such as when its input doesn't parse.
+    pub fn fail(&mut self, day: u32, error: &anyhow::Error) {
+        self.failures.push((day, format!("{error:#}")));
+    }
+
+    pub fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    pub fn total(&self) -> Duration {
+        self.days.iter().map(|(_, timings)| timings.total()).sum()
+    }
+
+    pub fn over_budget(&self) -> bool {
+        self.total() > self.budget
+    }
+
+    /// The slowest days, slowest first.
+    pub fn slowest(&self) -> Vec<&DayTimings> {
+        let mut days: Vec<_> = self.days.iter().map(|(_, timings)| timings).collect();
+        days.sort_by_key(|timings| std::cmp::Reverse(timings.total()));
+        days.truncate(SLOWEST);
+        days
+    }
+
+    fn share(&self, time: Duration) -> f64 {
+        let total = self.total().as_secs_f64();
+        if total == 0.0 {
+            0.0
+        } else {
+            time.as_secs_f64() / total * 100.0
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slowest: Vec<u32> = self.slowest().iter().map(|timings| timings.day).collect();
+
+        writeln!(
+            f,
+            "{:>3}  {:<28} {:>11} {:>11} {:>11} {:>11} {:>11}",
+            "Day", "Title", "Parse", "Part 1", "Part 2", "Total", "Cumulative"
+        )?;
+        let mut cumulative = Duration::ZERO;
+        for (title, timings) in &self.days {
+            cumulative += timings.total();
+            let marker = if slowest.contains(&timings.day) { "  <- slow" } else { "" };
+            writeln!(
+                f,
+                "{:>3}  {:<28} {:>11.2?} {:>11.2?} {:>11.2?} {:>11.2?} {:>11.2?}{marker}",
+                timings.day,
+                title,
+                timings.parse.median,
+                timings.part1.median,
+                timings.part2.median,
+                timings.total(),
+                cumulative,
+            )?;
+        }
+
+        writeln!(f)?;
+        let slowest: Vec<String> = self
+            .slowest()
+            .iter()
+            .map(|timings| format!("day {} ({:.2?}, {:.0}%)", timings.day, timings.total(), self.share(timings.total())))
+            .collect();
+        writeln!(f, "Slowest: {}", slowest.join(", "))?;
+        for (day, error) in &self.failures {
+            writeln!(f, "Failed: day {day}: {error}")?;
+        }
+
+        let verdict = if self.over_budget() { "OVER BUDGET" } else { "within budget" };
+        write!(f, "Total: {:.2?} of a {:.2?} budget", self.total(), self.budget)?;
+        if !self.budget.is_zero() {
+            write!(f, " ({:.0}%)", self.total().as_secs_f64() / self.budget.as_secs_f64() * 100.0)?;
+        }
+        write!(f, ", {verdict}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+
+    fn timings(day: u32, ms: u64) -> DayTimings {
+        let stats = |ms| {
+            let time = Duration::from_millis(ms);
+            Stats { median: time, min: time, mean: time, stddev: Duration::ZERO }
+        };
+        DayTimings { day, iterations: 1, parse: stats(0), part1: stats(ms / 2), part2: stats(ms - ms / 2) }
+    }
+
+    fn report(budget_ms: u64) -> Report {
+        let mut report = Report::new(Duration::from_millis(budget_ms));
+        for (day, ms) in [(1, 2), (6, 400), (7, 100), (11, 20), (16, 300)] {
+            report.add("Title", timings(day, ms));
+        }
+        report
+    }
+
+    #[test]
+    fn totals_and_budget() {
+        let report = report(1000);
+        assert_eq!(Duration::from_millis(822), report.total());
+        assert!(!report.over_budget());
+        assert_eq!(vec![6, 16, 7], report.slowest().iter().map(|timings| timings.day).collect::<Vec<_>>());
+        assert!(Report { budget: Duration::from_millis(800), ..report }.over_budget());
+    }
+
+    #[test]
+    fn table() {
+        let table = report(800).to_string();
+        let lines: Vec<_> = table.lines().collect();
+        assert!(lines[0].starts_with("Day  Title"));
+        assert!(lines[2].contains("400.00ms") && lines[2].ends_with("402.00ms  <- slow"));
+        assert!(!lines[4].contains("<- slow"));
+        assert_eq!("Slowest: day 6 (400.00ms, 49%), day 16 (300.00ms, 36%), day 7 (100.00ms, 12%)", lines[7]);
+        assert_eq!("Total: 822.00ms of a 800.00ms budget (103%), OVER BUDGET", lines[8]);
+
+        let mut report = report(0);
+        report.fail(9, &anyhow::anyhow!("Invalid input for day 9"));
+        assert!(report.has_failures());
+        let table = report.to_string();
+        assert!(table.contains("\nFailed: day 9: Invalid input for day 9\n"));
+        assert_eq!(Some("Total: 822.00ms of a 0.00ns budget, OVER BUDGET"), table.lines().last());
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+/// Placeholder left in generated days when the puzzle title isn't known yet.
+pub const TITLE_PLACEHOLDER: &str = "<TITLE>";
+
+/// Generates new days from `template/day.rs` and registers them in `src/days/mod.rs`.
+pub struct Scaffold {
+    root: PathBuf,
+}
+
+impl Scaffold {
+    /// Creates a scaffold for the repository rooted at `root`.
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self { root: root.as_ref().to_path_buf() }
+    }
+
+    pub fn day_path(&self, day: u32) -> PathBuf {
+        self.root.join("src/days").join(format!("day{day:02}.rs"))
+    }
+
+    /// Writes the solver for `day` and adds it to the registry, refusing to touch a day that already exists.
+    pub fn create_day(&self, day: u32, title: &str) -> Result<PathBuf> {
+        if !(1..=25).contains(&day) {
+            bail!("Day must be between 1 and 25, got {day}");
+        }
+
+        let day_path = self.day_path(day);
+        if day_path.exists() {
+            bail!("{} already exists", day_path.display());
+        }
+
+        let template_path = self.root.join("template/day.rs");
+        let template = fs::read_to_string(&template_path)
+            .with_context(|| format!("Failed to read {}", template_path.display()))?;
+        let mod_path = self.root.join("src/days/mod.rs");
+        let registry = fs::read_to_string(&mod_path).with_context(|| format!("Failed to read {}", mod_path.display()))?;
+        let registry = register(&registry, day)?;
+
+        fs::write(&day_path, render(&template, day, title))
+            .with_context(|| format!("Failed to write {}", day_path.display()))?;
+        fs::write(&mod_path, registry).with_context(|| format!("Failed to write {}", mod_path.display()))?;
+
+        Ok(day_path)
+    }
+}
+
+/// Fills in the day number and title placeholders of the day template.
+pub fn render(template: &str, day: u32, title: &str) -> String {
+    template
+        .replace("const DAY: u32 = NN;", &format!("const DAY: u32 = {day};"))
+        .replace("NN", &format!("{day:02}"))
+        .replace(TITLE_PLACEHOLDER, &title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Adds `pub mod dayNN;` and `&dayNN::DayNN,` to the contents of `src/days/mod.rs`, keeping both lists in order.
+pub fn register(registry: &str, day: u32) -> Result<String> {
+    let module = format!("day{day:02}");
+    let declaration = format!("pub mod {module};");
+    let entry = format!("    &{module}::Day{day:02},");
+
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        bail!("Day {day} is already registered in src/days/mod.rs");
+    }
+
+    let modules: Vec<usize> = (0..lines.len()).filter(|&i| lines[i].starts_with("pub mod day")).collect();
+    let Some(&last_module) = modules.last() else {
+        bail!("No `pub mod dayNN;` declarations found in src/days/mod.rs");
+    };
+    let module_at = modules.iter().copied().find(|&i| lines[i] > declaration).unwrap_or(last_module + 1);
+    lines.insert(module_at, declaration);
+
+    let Some(start) = lines.iter().position(|line| line.starts_with("pub const DAYS")) else {
+        bail!("No `DAYS` registry found in src/days/mod.rs");
+    };
+    let Some(end) = lines[start..].iter().position(|line| line.trim() == "];").map(|i| start + i) else {
+        bail!("The `DAYS` registry in src/days/mod.rs is not closed");
+    };
+    let entry_at = (start + 1..end).find(|&i| lines[i] > entry).unwrap_or(end);
+    lines.insert(entry_at, entry);
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+    Ok(registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+use crate::DynSolution;
+
+pub mod day01;
+pub mod day03;
+
+/// Every solved day, in order.
+pub const DAYS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn renders_template() {
+        let template = "pub struct DayNN;\nimpl Solution for DayNN {\n    const DAY: u32 = NN;\n    const TITLE: &'static str = \"<TITLE>\";\n}\n";
+        let rendered = render(template, 6, "Guard \"Gallivant\"");
+        assert_eq!(
+            "pub struct Day06;\nimpl Solution for Day06 {\n    const DAY: u32 = 6;\n    const TITLE: &'static str = \"Guard \\\"Gallivant\\\"\";\n}\n",
+            rendered
+        );
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let registry = register(REGISTRY, 2).unwrap();
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registry.contains("    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];"));
+
+        let registry = register(&registry, 4).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day04;\n\n"));
+        assert!(registry.contains("    &day03::Day03,\n    &day04::Day04,\n];"));
+
+        assert!(register(&registry, 3).is_err());
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("template")).unwrap();
+        fs::write(root.join("template/day.rs"), "pub struct DayNN;\n").unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+
+        let scaffold = Scaffold::new(&root);
+        let path = scaffold.create_day(2, TITLE_PLACEHOLDER).unwrap();
+        assert_eq!("pub struct Day02;\n", fs::read_to_string(path).unwrap());
+        assert!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap().contains("pub mod day02;"));
+
+        fs::write(scaffold.day_path(2), "// solved").unwrap();
+        assert!(scaffold.create_day(2, "Red-Nosed Reports").is_err());
+        assert_eq!("// solved", fs::read_to_string(scaffold.day_path(2)).unwrap());
+        assert!(scaffold.create_day(26, TITLE_PLACEHOLDER).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use anyhow::{ensure, Context, Result};
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+
+/// Puzzles unlock at midnight US Eastern, which is always UTC-5 in December.
+const UNLOCK_OFFSET_HOURS: i32 = -5;
+pub const LAST_DAY: u32 = 25;
+
+/// Source of the current time, so scheduling can be tested without waiting for December.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Works out which puzzles of an event have unlocked.
+pub struct Schedule<C: Clock> {
+    clock: C,
+    year: u32,
+}
+
+impl<C: Clock> Schedule<C> {
+    pub fn new(clock: C, year: u32) -> Self {
+        Self { clock, year }
+    }
+
+    /// When the puzzle for `day` unlocks, or an error if the event has no such day.
+    pub fn unlock_time(&self, day: u32) -> Result<DateTime<Utc>> {
+        ensure!((1..=LAST_DAY).contains(&day), "Day {day} is not a puzzle day, pick one from 1 to {LAST_DAY}");
+        let unlock = eastern()
+            .with_ymd_and_hms(self.year as i32, 12, day, 0, 0, 0)
+            .single()
+            .with_context(|| format!("December {day} {} is not a valid date", self.year))?;
+        Ok(unlock.with_timezone(&Utc))
+    }
+
+    /// Whether `day` has unlocked, which days outside the event never do.
+    pub fn is_unlocked(&self, day: u32) -> bool {
+        self.unlock_time(day).is_ok_and(|unlock| self.clock.now() >= unlock)
+    }
+
+    /// How long until `day` unlocks, or `None` if it already has.
+    pub fn time_until_unlock(&self, day: u32) -> Result<Option<Duration>> {
+        Ok((self.unlock_time(day)? - self.clock.now()).to_std().ok().filter(|wait| !wait.is_zero()))
+    }
+
+    /// Today's puzzle in US Eastern time, or `None` outside the event.
+    pub fn current_day(&self) -> Option<u32> {
+        let now = self.clock.now().with_timezone(&eastern());
+        (now.year() == self.year as i32 && now.month() == 12 && now.day() <= LAST_DAY).then_some(now.day())
+    }
+
+    /// The next puzzle to unlock, or `None` once every puzzle has.
+    pub fn next_day(&self) -> Option<u32> {
+        (1..=LAST_DAY).find(|&day| !self.is_unlocked(day))
+    }
+}
+
+/// Formats a wait like `1h 02m 03s`, leaving out leading zero units.
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m {seconds:02}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+fn eastern() -> FixedOffset {
+    FixedOffset::east_opt(UNLOCK_OFFSET_HOURS * 3600).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn schedule_at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Schedule<FixedClock> {
+        let now = Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap();
+        Schedule::new(FixedClock(now), 2024)
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        let schedule = schedule_at(2024, 12, 6, 4, 59);
+        assert_eq!(Utc.with_ymd_and_hms(2024, 12, 6, 5, 0, 0).unwrap(), schedule.unlock_time(6).unwrap());
+        assert!(schedule.is_unlocked(5));
+        assert!(!schedule.is_unlocked(6));
+        assert_eq!(Some(Duration::from_secs(60)), schedule.time_until_unlock(6).unwrap());
+        assert_eq!(None, schedule.time_until_unlock(5).unwrap());
+
+        let schedule = schedule_at(2024, 12, 6, 5, 0);
+        assert!(schedule.is_unlocked(6));
+        assert_eq!(None, schedule.time_until_unlock(6).unwrap());
+    }
+
+    #[test]
+    fn rejects_days_outside_the_event() {
+        let schedule = schedule_at(2024, 12, 6, 5, 0);
+        assert!(schedule.unlock_time(0).is_err());
+        assert!(schedule.time_until_unlock(26).is_err());
+        assert!(schedule.time_until_unlock(40).is_err());
+        assert!(!schedule.is_unlocked(32));
+    }
+
+    #[test]
+    fn current_day() {
+        // Still the 5th in US Eastern even though it is the 6th in UTC.
+        assert_eq!(Some(5), schedule_at(2024, 12, 6, 3, 0).current_day());
+        assert_eq!(Some(6), schedule_at(2024, 12, 6, 5, 0).current_day());
+        assert_eq!(None, schedule_at(2024, 12, 1, 4, 0).current_day());
+        assert_eq!(None, schedule_at(2024, 12, 26, 12, 0).current_day());
+        assert_eq!(None, schedule_at(2025, 12, 6, 12, 0).current_day());
+        assert_eq!(None, schedule_at(2024, 7, 6, 12, 0).current_day());
+    }
+
+    #[test]
+    fn waits() {
+        assert_eq!("42s", format_wait(Duration::from_secs(42)));
+        assert_eq!("2m 05s", format_wait(Duration::from_secs(125)));
+        assert_eq!("26h 00m 01s", format_wait(Duration::from_secs(26 * 3600 + 1)));
+    }
+
+    #[test]
+    fn next_day() {
+        assert_eq!(Some(1), schedule_at(2024, 11, 20, 0, 0).next_day());
+        assert_eq!(Some(7), schedule_at(2024, 12, 6, 5, 0).next_day());
+        assert_eq!(None, schedule_at(2024, 12, 25, 5, 0).next_day());
+    }
+}
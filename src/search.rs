@@ -0,0 +1,319 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Everything a search learned: how far each reached node is from the nearest start, and how it got there.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    distances: FxHashMap<N, usize>,
+    /// Every neighbour a node can be reached from at its shortest distance, so all shortest paths can be recovered.
+    predecessors: FxHashMap<N, Vec<N>>,
+    /// Goal nodes reached at the shortest goal distance.
+    goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &FxHashMap<N, usize> {
+        &self.distances
+    }
+
+    /// Nodes `node` can be reached from on a shortest path, empty for start nodes.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goal reached first, or `None` if no goal was reachable.
+    pub fn goal(&self) -> Option<&N> {
+        self.goals.first()
+    }
+
+    /// Every goal reached at the shortest goal distance.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Distance to the nearest goal.
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goal().and_then(|goal| self.distance(goal))
+    }
+
+    /// One shortest path from a start to `node`, including both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every node lying on at least one shortest path to any of `ends`.
+    pub fn nodes_on_shortest_paths<'a>(&self, ends: impl IntoIterator<Item = &'a N>) -> FxHashSet<N>
+    where
+        N: 'a,
+    {
+        let mut nodes = FxHashSet::default();
+        let mut stack: Vec<N> = ends.into_iter().filter(|end| self.distances.contains_key(end)).cloned().collect();
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+
+        nodes
+    }
+
+    fn new(starts: impl IntoIterator<Item = N>) -> Self {
+        Self {
+            distances: starts.into_iter().map(|start| (start, 0)).collect(),
+            predecessors: FxHashMap::default(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records reaching `next` from `node` at `distance`, returning whether it's a new shortest distance.
+    fn relax(&mut self, node: &N, next: N, distance: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(&best) if distance > best => false,
+            Some(&best) if distance == best => {
+                let parents = self.predecessors.entry(next).or_default();
+                if !parents.contains(node) {
+                    parents.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+///
+/// Stops once every node at the nearest goal's distance has been seen; pass `|_| false` as `is_goal` to
+/// explore everything reachable.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(starts);
+    let mut queue: VecDeque<(N, usize)> = result.distances.keys().map(|start| (start.clone(), 0)).collect();
+    let mut goal_distance = None;
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if goal_distance.is_some_and(|goal| distance > goal) {
+            break;
+        }
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            result.goals.push(node);
+            continue;
+        }
+
+        for next in successors(&node) {
+            if result.relax(&node, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm over weighted steps.
+///
+/// Stops once the nearest goal's distance has been settled, keeping every goal and predecessor at that distance.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search, guided by a `heuristic` that must never overestimate the remaining distance to a goal.
+///
+/// With a consistent heuristic the predecessors still describe every shortest path to the goals.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut result = SearchResult::new(starts);
+    let mut frontier: BinaryHeap<Entry<N>> = result
+        .distances
+        .keys()
+        .map(|start| Entry { estimate: heuristic(start), distance: 0, node: start.clone() })
+        .collect();
+    let mut goal_distance = None;
+
+    while let Some(Entry { estimate, distance, node }) = frontier.pop() {
+        if goal_distance.is_some_and(|goal| estimate > goal) {
+            break;
+        }
+        if result.distances.get(&node).is_some_and(|&best| distance > best) {
+            continue;
+        }
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            result.goals.push(node);
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+            if result.relax(&node, next.clone(), next_distance) {
+                frontier.push(Entry { estimate: next_distance + heuristic(&next), distance: next_distance, node: next });
+            }
+        }
+    }
+
+    result
+}
+
+/// Heap entry ordered so the lowest estimate pops first.
+struct Entry<N> {
+    estimate: usize,
+    distance: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate && self.distance == other.distance
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate).then(self.distance.cmp(&other.distance))
+    }
+}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Topology, Vector};
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open_neighbors(grid: &Grid<char>, pos: Vector) -> Vec<Vector> {
+        grid.neighbors4(pos).filter(|&next| grid[next] != '#').collect()
+    }
+
+    #[test]
+    fn bfs_finds_all_shortest_paths() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let result = bfs([start], |&pos| open_neighbors(&grid, pos), |&pos| pos == end);
+        assert_eq!(Some(5), result.goal_distance());
+        assert_eq!(Some(&end), result.goal());
+
+        let path = result.path_to(&end).unwrap();
+        assert_eq!(6, path.len());
+        assert_eq!(start, path[0]);
+        assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+
+        // Around either side of the wall in the middle, and both ways round the corner at the end.
+        let on_paths = result.nodes_on_shortest_paths([&end]);
+        assert_eq!(10, on_paths.len());
+        assert!(!on_paths.contains(&Vector(1, 1)));
+    }
+
+    #[test]
+    fn bfs_explores_everything_without_a_goal() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let result = bfs([Vector(0, 0)], |&pos| open_neighbors(&grid, pos), |_| false);
+        assert_eq!(None, result.goal());
+        assert_eq!(10, result.distances().len());
+        assert_eq!(Some(3), result.distance(&Vector(1, 2)));
+        assert_eq!(None, result.distance(&Vector(0, 3)));
+        assert!(result.predecessors(&Vector(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // Moving right is cheap and moving down gets cheaper further right, so the best route hugs the top.
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = Vector(2, 3);
+        let successors = |&pos: &Vector| {
+            open_neighbors(&grid, pos)
+                .into_iter()
+                .map(move |next| {
+                    let cost = if next.1 > pos.1 {
+                        1
+                    } else if next.0 > pos.0 {
+                        5 - pos.1 as usize
+                    } else {
+                        5
+                    };
+                    (next, cost)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let dijkstra = dijkstra([Vector(0, 0)], successors, |&pos| pos == end);
+        let astar = astar([Vector(0, 0)], successors, |&pos| pos.manhattan(end) as usize, |&pos| pos == end);
+
+        assert_eq!(Some(8), dijkstra.goal_distance());
+        assert_eq!(dijkstra.goal_distance(), astar.goal_distance());
+        assert_eq!(dijkstra.path_to(&end), astar.path_to(&end));
+        assert_eq!(
+            vec![Vector(0, 0), Vector(0, 1), Vector(0, 2), Vector(1, 2), Vector(1, 3), Vector(2, 3)],
+            dijkstra.path_to(&end).unwrap()
+        );
+    }
+
+    #[test]
+    fn multiple_starts_and_goals() {
+        let result = dijkstra(
+            [0, 10],
+            |&n: &i32| [(n + 1, 1), (n - 1, 1)],
+            |&n| n == 5,
+        );
+        assert_eq!(Some(5), result.goal_distance());
+        assert_eq!(vec![5], result.goals());
+        // 5 is equally far from both starts.
+        assert_eq!(11, result.nodes_on_shortest_paths(result.goals()).len());
+    }
+}
@@ -0,0 +1,28 @@
+use anyhow::*;
+use std::fmt::Display;
+use std::io::BufRead;
+use crate::parse;
+use crate::Solution;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u32 = NN;
+    const TITLE: &'static str = "<TITLE>";
+
+    type Input = Vec<String>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let input = parse::read_input(reader)?;
+        Ok(parse::lines(&input).map(|line| line.text.to_string()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        // TODO: Solve Part 1 of the puzzle
+        Ok(input.len())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<impl Display> {
+        Ok(0)
+    }
+}
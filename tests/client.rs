@@ -0,0 +1,110 @@
+mod common;
+
+use adv_code_2024::client::{AocClient, Hint, SubmitOutcome};
+use adv_code_2024::config::{Config, Profile};
+use common::MockServer;
+
+fn client(server: &MockServer) -> AocClient {
+    let config = Config {
+        base_url: server.base_url.clone(),
+        ..Config::default()
+    };
+    AocClient::new(&config, "secret").unwrap()
+}
+
+#[tokio::test]
+async fn download_input_sends_session() {
+    let server = MockServer::start(vec![(200, "1 2\n3 4\n")]).await;
+    let client = client(&server);
+
+    let input = client.download_input(6).await.unwrap();
+    assert_eq!("1 2\n3 4\n", input);
+
+    let requests = server.requests().await;
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("/2024/day/6/input", requests[0].path);
+    assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+}
+
+#[tokio::test]
+async fn profiles_use_their_own_session() {
+    let server = MockServer::start(vec![(200, "5 6\n")]).await;
+    let config: Config = toml::from_str(&format!(
+        "base_url = \"{}\"\n[profiles.alice]\nsession_env = \"AOC_TEST_SESSION_ALICE\"\n",
+        server.base_url
+    ))
+    .unwrap();
+    let alice = config.profile("alice").unwrap();
+    assert!(AocClient::for_profile(&config, &alice).is_err());
+
+    std::env::set_var("AOC_TEST_SESSION_ALICE", "alices-secret");
+    AocClient::for_profile(&config, &alice).unwrap().download_input(6).await.unwrap();
+
+    let requests = server.requests().await;
+    assert_eq!(Some("session=alices-secret"), requests[0].header("cookie"));
+    assert_eq!(Profile::DEFAULT, config.profile("default").unwrap().name);
+}
+
+#[tokio::test]
+async fn download_input_reports_server_errors() {
+    let server = MockServer::start(vec![(400, "Please log in")]).await;
+    let client = client(&server);
+
+    assert!(client.download_input(6).await.is_err());
+    server.requests().await;
+}
+
+#[tokio::test]
+async fn submit_posts_answer() {
+    let server = MockServer::start(vec![
+        (200, "<main><article><p>That's the right answer!</p></article></main>"),
+        (200, "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>"),
+        (200, "<main><article><p>You gave an answer too recently.  You have 2m 3s left to wait.</p></article></main>"),
+    ])
+    .await;
+    let client = client(&server);
+
+    assert_eq!(SubmitOutcome::Correct, client.submit(7, 2, "11387").await.unwrap());
+    assert_eq!(SubmitOutcome::Wrong(Some(Hint::TooLow)), client.submit(7, 2, "1").await.unwrap());
+    assert_eq!(
+        SubmitOutcome::RateLimited(std::time::Duration::from_secs(123)),
+        client.submit(7, 2, "2").await.unwrap()
+    );
+
+    let requests = server.requests().await;
+    assert_eq!("POST", requests[0].method);
+    assert_eq!("/2024/day/7/answer", requests[0].path);
+    assert_eq!("level=2&answer=11387", requests[0].body);
+    assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+}
+
+#[tokio::test]
+async fn year_and_user_agent_come_from_config() {
+    let server = MockServer::start(vec![(200, "input")]).await;
+    let config = Config {
+        year: 2022,
+        base_url: format!("{}/", server.base_url),
+        user_agent: "aoc-tests".to_string(),
+        ..Config::default()
+    };
+    let client = AocClient::new(&config, "secret").unwrap();
+
+    client.download_input(25).await.unwrap();
+
+    let requests = server.requests().await;
+    assert_eq!("/2022/day/25/input", requests[0].path);
+    assert_eq!(Some("aoc-tests"), requests[0].header("user-agent"));
+}
+
+#[tokio::test]
+async fn fetch_puzzle_requests_day_page() {
+    let server = MockServer::start(vec![(200, "<article><h2>--- Day 3: Mull It Over ---</h2></article>")]).await;
+    let client = client(&server);
+
+    let page = client.fetch_puzzle(3).await.unwrap();
+    assert!(page.contains("Mull It Over"));
+
+    let requests = server.requests().await;
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("/2024/day/3", requests[0].path);
+}
@@ -0,0 +1,181 @@
+use std::fs;
+use std::path::Path;
+
+use adv_code_2024::{days, Params};
+use adv_code_2024::parse::ParseError;
+use serde::Deserialize;
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+#[derive(Deserialize)]
+struct Manifest {
+    example: Vec<Example>,
+}
+
+#[derive(Deserialize)]
+struct Example {
+    day: u32,
+    file: String,
+    part1: Option<String>,
+    part2: Option<String>,
+    #[serde(default)]
+    params: Params,
+}
+
+fn load_manifest() -> Manifest {
+    let manifest = fs::read_to_string(Path::new(EXAMPLES_DIR).join("examples.toml"))
+        .expect("Failed to read examples/examples.toml");
+    toml::from_str(&manifest).expect("Failed to parse examples/examples.toml")
+}
+
+/// Runs every example registered for `day` and checks the answers it lists.
+fn check_day(day: u32) {
+    let solution = days::get(day).unwrap_or_else(|| panic!("Day {day} is not registered"));
+    let manifest = load_manifest();
+    let examples: Vec<_> = manifest.example.iter().filter(|example| example.day == day).collect();
+    assert!(!examples.is_empty(), "Day {day} has no examples in examples/examples.toml");
+
+    for example in examples {
+        let input = fs::read(Path::new(EXAMPLES_DIR).join(&example.file))
+            .unwrap_or_else(|e| panic!("Failed to read examples/{}: {e}", example.file));
+        let parsed = solution
+            .parse_with(&input, &example.params)
+            .unwrap_or_else(|e| panic!("Failed to parse examples/{}: {e:#}", example.file));
+
+        if let Some(expected) = &example.part1 {
+            let answer = solution.part1(parsed.as_ref()).unwrap();
+            assert_eq!(expected, &answer, "Part 1 of examples/{}", example.file);
+        }
+
+        if let Some(expected) = &example.part2 {
+            let answer = solution.part2(parsed.as_ref()).unwrap();
+            assert_eq!(expected, &answer, "Part 2 of examples/{}", example.file);
+        }
+    }
+}
+
+macro_rules! example_tests {
+    ($($name:ident: $day:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+example_tests! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+}
+
+#[test]
+fn manifest_entries_are_valid() {
+    for example in load_manifest().example {
+        assert!(days::get(example.day).is_some(), "Day {} is not registered", example.day);
+        assert!(
+            Path::new(EXAMPLES_DIR).join(&example.file).is_file(),
+            "examples/{} does not exist",
+            example.file
+        );
+        assert!(
+            example.part1.is_some() || example.part2.is_some(),
+            "examples/{} has no expected answers",
+            example.file
+        );
+    }
+}
+
+#[test]
+fn malformed_inputs_point_at_the_bad_line() {
+    let input = fs::read_to_string(Path::new(EXAMPLES_DIR).join("07-1.txt")).unwrap();
+    let corrupted = input.replacen("83: 17 5", "83: 17 5x", 1);
+
+    let err = match days::get(7).unwrap().parse(corrupted.as_bytes()) {
+        Ok(_) => panic!("A corrupted input should not parse"),
+        Err(err) => err,
+    };
+    assert!(format!("{err:#}").starts_with("Invalid input for day 7: line 3, column 8"));
+
+    let parse_error = err.chain().find_map(|cause| cause.downcast_ref::<ParseError>()).unwrap();
+    assert_eq!((3, 2), (parse_error.line, parse_error.width));
+    assert_eq!("83: 17 5x", parse_error.text);
+
+    // Input cut off part way through a section is caught too.
+    let truncated = "#####\n#..O@\n#.\n\n<>\n";
+    assert!(days::get(15).unwrap().parse(truncated.as_bytes()).is_err());
+
+    // So is an unknown cell in a map.
+    let warehouse = fs::read_to_string(Path::new(EXAMPLES_DIR).join("15-1.txt")).unwrap();
+    assert_eq!((2, 4), parse_error_at(15, &warehouse.replacen("#..O..O.O#", "#..x..O.O#", 1)));
+}
+
+/// Parses `input` for `day`, expecting it to fail at `(line, column)`.
+fn parse_error_at(day: u32, input: &str) -> (usize, usize) {
+    let err = match days::get(day).unwrap().parse(input.as_bytes()) {
+        Ok(_) => panic!("A corrupted day {day} input should not parse"),
+        Err(err) => err,
+    };
+    let parse_error = err.chain().find_map(|cause| cause.downcast_ref::<ParseError>()).unwrap();
+    (parse_error.line, parse_error.column)
+}
+
+#[test]
+fn malformed_maps_point_at_the_bad_cell() {
+    let warehouse = fs::read_to_string(Path::new(EXAMPLES_DIR).join("15-1.txt")).unwrap();
+    assert_eq!((5, 5), parse_error_at(15, &warehouse.replacen("#..O..O.O#", "#..O@.O.O#", 1)));
+    assert_eq!((1, 1), parse_error_at(15, &warehouse.replacen('@', ".", 1)));
+    assert_eq!((1, 3), parse_error_at(15, &warehouse.replacen("##########", "##.#######", 1)));
+
+    let maze = fs::read_to_string(Path::new(EXAMPLES_DIR).join("16-1.txt")).unwrap();
+    assert_eq!((2, 3), parse_error_at(16, &maze.replacen("#.......#", "#.?.....#", 1)));
+    assert_eq!((1, 1), parse_error_at(16, &maze.replacen('E', ".", 1)));
+    assert_eq!((14, 2), parse_error_at(16, &maze.replacen("#.......#", "#.S.....#", 1)));
+}
+
+#[test]
+fn day18_rejects_bytes_outside_memory() {
+    let day = days::get(18).unwrap();
+    let err = match day.parse(b"5,4\n71,3\n") {
+        Ok(_) => panic!("A byte outside memory should not parse"),
+        Err(err) => err,
+    };
+    assert_eq!(2, err.chain().find_map(|cause| cause.downcast_ref::<ParseError>()).unwrap().line);
+
+    // Nothing blocks the exit, so there is no first blocking byte to report.
+    for input in ["", "5,4\n"] {
+        let parsed = day.parse(input.as_bytes()).unwrap();
+        assert!(day.part2(parsed.as_ref()).is_err());
+    }
+}
+
+#[test]
+fn day03_points_at_numbers_that_overflow() {
+    let input = "mul(2,4)do()\nxmul(3,99999999999999999999999)\n";
+    assert_eq!((2, 8), parse_error_at(3, input));
+}
+
+#[test]
+fn day17_rejects_values_outside_three_bits() {
+    let registers = "Register A: 729\nRegister B: 0\nRegister C: 0\n\n";
+    assert_eq!((5, 14), parse_error_at(17, &format!("{registers}Program: 0,1,8,4\n")));
+    assert_eq!((5, 12), parse_error_at(17, &format!("{registers}Program: 0,7,5,4\n")));
+    assert!(days::get(17).unwrap().parse(format!("{registers}Program: 1,7,5,4\n").as_bytes()).is_ok());
+}
@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::Path;
+
+use adv_code_2024::days::day06::Day06;
+use adv_code_2024::days::day14::Day14;
+use adv_code_2024::days::day15::Day15;
+use adv_code_2024::days::day16::Day16;
+use adv_code_2024::recorder::Recorder;
+use adv_code_2024::render::Frame;
+use adv_code_2024::{Params, Solution};
+
+const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+fn example(file: &str) -> String {
+    fs::read_to_string(Path::new(EXAMPLES_DIR).join(file)).unwrap_or_else(|e| panic!("Failed to read examples/{file}: {e}"))
+}
+
+fn count(frame: &Frame, ch: char) -> usize {
+    frame.to_ascii().chars().filter(|&c| c == ch).count()
+}
+
+fn small_restroom() -> <Day14 as Solution>::Input {
+    Day14::parse_with(example("14-1.txt").as_bytes(), &Params::new([("width", 11), ("height", 7)])).unwrap()
+}
+
+#[test]
+fn renders_the_robots() {
+    let start = "\
+#.##.......
+...........
+...........
+......##.##
+#.#........
+.........#.
+.......#...
+";
+    let frame = small_restroom().render();
+    assert_eq!((11, 7), (frame.width(), frame.height()));
+    assert_eq!(start, frame.to_ascii());
+}
+
+#[test]
+fn renders_the_best_paths() {
+    for (file, tiles) in [("16-1.txt", 45), ("16-2.txt", 64)] {
+        let input = example(file);
+        let maze = Day16::parse(input.as_bytes()).unwrap();
+        let frame = maze.render_best_paths();
+
+        // Every tile on a best path, including the start and end, is marked.
+        assert_eq!(tiles, count(&frame, 'O'), "Best path tiles in examples/{file}");
+        assert_eq!(0, count(&frame, 'S') + count(&frame, 'E'));
+        assert_eq!(input.matches('#').count(), count(&frame, '#'));
+    }
+}
+
+#[test]
+fn records_the_guard_walking_off_the_map() {
+    let input = example("06-1.txt");
+    let (guard, map) = Day06::parse(input.as_bytes()).unwrap();
+    let mut recorder = Recorder::new();
+    guard.record_walk(&map, &mut recorder);
+
+    // The start, then one frame per step or turn, the last of which leaves the map.
+    assert_eq!(56, recorder.len());
+    assert_eq!(input, recorder.frames()[0].to_ascii());
+    let last = recorder.frames().last().unwrap();
+    assert_eq!(41, count(last, 'X'));
+    assert_eq!(0, count(last, '^') + count(last, '>') + count(last, 'v') + count(last, '<'));
+}
+
+#[test]
+fn stops_recording_a_guard_stuck_in_a_loop() {
+    let input = example("06-1.txt").replacen(".#..^", ".#.#^", 1);
+    let (guard, map) = Day06::parse(input.as_bytes()).unwrap();
+    let mut recorder = Recorder::new();
+    guard.record_walk(&map, &mut recorder);
+
+    assert_eq!(23, recorder.len());
+    assert_eq!(input, recorder.frames()[0].to_ascii());
+    // Back at the start facing up, which is where the loop begins again.
+    assert_eq!(".#.#^XXXX.", recorder.frames().last().unwrap().to_ascii().lines().nth(6).unwrap());
+}
+
+#[test]
+fn records_the_robots() {
+    let mut recorder = Recorder::new();
+    small_restroom().record(100, &mut recorder);
+
+    assert_eq!(101, recorder.len());
+    assert_eq!(small_restroom().render(), recorder.frames()[0]);
+    let after_100 = "\
+......#..#.
+...........
+#..........
+.##........
+.....#.....
+...##......
+.#....#....
+";
+    assert_eq!(after_100, recorder.frames()[100].to_ascii());
+}
+
+#[test]
+fn records_the_warehouse_moves() {
+    let input = example("15-1.txt");
+    let warehouse = Day15::parse(input.as_bytes()).unwrap();
+    let mut recorder = Recorder::new();
+    warehouse.record_moves(&mut recorder);
+
+    // The start and one frame per move.
+    assert_eq!(701, recorder.len());
+    assert_eq!(input.split("\n\n").next().unwrap(), recorder.frames()[0].to_ascii().trim_end());
+    let end = "\
+##########
+#.O.O.OOO#
+#........#
+#OO......#
+#OO@.....#
+#O#.....O#
+#O.....OO#
+#O.....OO#
+#OO....OO#
+##########
+";
+    assert_eq!(end, recorder.frames().last().unwrap().to_ascii());
+
+    let mut every_100 = Recorder::every(100);
+    warehouse.record_moves(&mut every_100);
+    assert_eq!(8, every_100.len());
+}